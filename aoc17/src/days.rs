@@ -0,0 +1,62 @@
+use common::Solution;
+use one;
+use two;
+use three;
+use four;
+use five;
+use six;
+use seven;
+use eight;
+use nine;
+use ten;
+use eleven;
+use twelve;
+use thirteen;
+use fourteen;
+use fifteen;
+use sixteen;
+use seventeen;
+
+/// Something to do with a day's `Solution`, whichever day it is
+pub trait Visit {
+    type Output;
+
+    fn visit<S: Solution>(self, day: u8) -> Self::Output;
+}
+
+struct Probe;
+
+impl Visit for Probe {
+    type Output = ();
+
+    fn visit<S: Solution>(self, _: u8) {}
+}
+
+/// Every day that has a `Solution`, in order
+pub fn solved() -> Vec<u8> {
+    (1..=25).filter(|&day| visit(day, Probe).is_some()).collect()
+}
+
+/// Hands the `Solution` for `day` to `v`, or returns `None` if that day isn't solved
+pub fn visit<V: Visit>(day: u8, v: V) -> Option<V::Output> {
+    Some(match day {
+        1 => v.visit::<one::Day>(day),
+        2 => v.visit::<two::Day>(day),
+        3 => v.visit::<three::Day>(day),
+        4 => v.visit::<four::Day>(day),
+        5 => v.visit::<five::Day>(day),
+        6 => v.visit::<six::Day>(day),
+        7 => v.visit::<seven::Day>(day),
+        8 => v.visit::<eight::Day>(day),
+        9 => v.visit::<nine::Day>(day),
+        10 => v.visit::<ten::Day>(day),
+        11 => v.visit::<eleven::Day>(day),
+        12 => v.visit::<twelve::Day>(day),
+        13 => v.visit::<thirteen::Day>(day),
+        14 => v.visit::<fourteen::Day>(day),
+        15 => v.visit::<fifteen::Day>(day),
+        16 => v.visit::<sixteen::Day>(day),
+        17 => v.visit::<seventeen::Day>(day),
+        _ => return None,
+    })
+}
@@ -0,0 +1,387 @@
+extern crate common;
+extern crate one;
+extern crate two;
+extern crate three;
+extern crate four;
+extern crate five;
+extern crate six;
+extern crate seven;
+extern crate eight;
+extern crate nine;
+extern crate ten;
+extern crate eleven;
+extern crate twelve;
+extern crate thirteen;
+extern crate fourteen;
+extern crate fifteen;
+extern crate sixteen;
+extern crate seventeen;
+
+use common::{Inputs, Solution};
+use days::Visit;
+use std::env;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::process;
+
+mod days;
+mod measure;
+mod verify;
+
+#[global_allocator]
+static ALLOC: measure::Counting = measure::Counting;
+
+const USAGE: &str = "usage: aoc17 run <day> [--part 1|2] [--input FILE|-] [--inputs DIR]
+       aoc17 verify [<day>...] [--record] [--inputs DIR]
+       aoc17 measure [<day>...] [--json] [--inputs DIR]";
+
+#[derive(Debug)]
+enum Error {
+    Usage(String),
+    Io(String, io::Error),
+    Solution(common::Error),
+    Unsolved(u8),
+    Answers(String, common::ParseError),
+    Unverified(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Usage(ref msg) => write!(f, "{}", msg),
+            Error::Io(ref path, ref e) => write!(f, "could not read {}: {}", path, e),
+            Error::Solution(ref e) => write!(f, "{}", e),
+            Error::Unsolved(day) => write!(f, "day {} has no solution yet", day),
+            Error::Answers(ref path, ref e) => write!(f, "could not parse {}: {}", path, e),
+            Error::Unverified(n) => write!(f, "{} answer(s) did not verify", n),
+        }
+    }
+}
+
+impl From<common::Error> for Error {
+    fn from(e: common::Error) -> Error {
+        Error::Solution(e)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run(Args),
+    Verify(VerifyArgs),
+    Measure(MeasureArgs),
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    day: u8,
+    part: Option<u8>,
+    input: Option<String>,
+    inputs: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct VerifyArgs {
+    days: Vec<u8>,
+    record: bool,
+    inputs: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct MeasureArgs {
+    days: Vec<u8>,
+    json: bool,
+    inputs: Option<String>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, Error> {
+    match args.next() {
+        Some(ref cmd) if cmd == "run" => parse_run(args).map(Command::Run),
+        Some(ref cmd) if cmd == "verify" => parse_verify(args).map(Command::Verify),
+        Some(ref cmd) if cmd == "measure" => parse_measure(args).map(Command::Measure),
+        Some(cmd) => Err(Error::Usage(format!("unknown command `{}`", cmd))),
+        None => Err(Error::Usage("missing command".into())),
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, Error> {
+    match day.parse() {
+        Ok(d) if (1..=25).contains(&d) => Ok(d),
+        _ => Err(Error::Usage(format!("`{}` is not a day between 1 and 25", day))),
+    }
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<Args, Error> {
+    let day = parse_day(&args.next().ok_or_else(|| Error::Usage("missing day".into()))?)?;
+    let mut parsed = Args {
+        day,
+        part: None,
+        input: None,
+        inputs: None,
+    };
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--part" => {
+                parsed.part = match args.next().as_ref().map(|s| &s[..]) {
+                    Some("1") => Some(1),
+                    Some("2") => Some(2),
+                    _ => return Err(Error::Usage("--part must be 1 or 2".into())),
+                };
+            },
+            "--input" => {
+                parsed.input = Some(args.next().ok_or_else(|| Error::Usage("--input needs a file name or `-`".into()))?);
+            },
+            "--inputs" => {
+                parsed.inputs = Some(args.next().ok_or_else(|| Error::Usage("--inputs needs a directory".into()))?);
+            },
+            a => return Err(Error::Usage(format!("unexpected argument `{}`", a))),
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_verify<I: Iterator<Item = String>>(mut args: I) -> Result<VerifyArgs, Error> {
+    let mut parsed = VerifyArgs {
+        days: vec![],
+        record: false,
+        inputs: None,
+    };
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--record" => parsed.record = true,
+            "--inputs" => {
+                parsed.inputs = Some(args.next().ok_or_else(|| Error::Usage("--inputs needs a directory".into()))?);
+            },
+            a if a.starts_with("--") => return Err(Error::Usage(format!("unexpected argument `{}`", a))),
+            day => parsed.days.push(parse_day(day)?),
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_measure<I: Iterator<Item = String>>(mut args: I) -> Result<MeasureArgs, Error> {
+    let mut parsed = MeasureArgs {
+        days: vec![],
+        json: false,
+        inputs: None,
+    };
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--json" => parsed.json = true,
+            "--inputs" => {
+                parsed.inputs = Some(args.next().ok_or_else(|| Error::Usage("--inputs needs a directory".into()))?);
+            },
+            a if a.starts_with("--") => return Err(Error::Usage(format!("unexpected argument `{}`", a))),
+            day => parsed.days.push(parse_day(day)?),
+        }
+    }
+    Ok(parsed)
+}
+
+// the days asked for, or every solved day if none were
+fn select_days(days: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let solved = days::solved();
+    if let Some(&day) = days.iter().find(|d| !solved.contains(d)) {
+        return Err(Error::Unsolved(day));
+    }
+    Ok(if days.is_empty() { solved } else { days })
+}
+
+fn inputs_dir(dir: &Option<String>) -> Inputs {
+    match *dir {
+        Some(ref dir) => Inputs::new(dir),
+        None => Inputs::locate(),
+    }
+}
+
+// an explicit --input wins, otherwise the day's file from the inputs directory
+fn load_input(args: &Args) -> Result<String, Error> {
+    if let Some(ref path) = args.input {
+        return read_input(path);
+    }
+    let inputs = inputs_dir(&args.inputs);
+    let path = inputs.path(args.day);
+    inputs.load(args.day).map_err(|e| Error::Io(path.display().to_string(), e))
+}
+
+fn read_input(path: &str) -> Result<String, Error> {
+    let mut input = String::new();
+    let result = if path == "-" {
+        io::stdin().read_to_string(&mut input)
+    } else {
+        File::open(path).and_then(|mut f| f.read_to_string(&mut input))
+    };
+    match result {
+        Ok(_) => Ok(input),
+        Err(e) => Err(Error::Io(path.into(), e)),
+    }
+}
+
+struct Solve<'a> {
+    input: &'a str,
+    part: Option<u8>,
+}
+
+impl<'a> Visit for Solve<'a> {
+    type Output = Result<Vec<(u8, String)>, Error>;
+
+    fn visit<S: Solution>(self, _: u8) -> Self::Output {
+        let parsed = S::parse(self.input)?;
+        let mut answers = vec![];
+        if self.part != Some(2) {
+            answers.push((1, S::part1(&parsed)?.to_string()));
+        }
+        if self.part != Some(1) {
+            answers.push((2, S::part2(&parsed)?.to_string()));
+        }
+        Ok(answers)
+    }
+}
+
+fn solve(day: u8, part: Option<u8>, input: &str) -> Result<Vec<(u8, String)>, Error> {
+    days::visit(day, Solve { input, part }).unwrap_or(Err(Error::Unsolved(day)))
+}
+
+fn run(args: Args) -> Result<(), Error> {
+    let input = load_input(&args)?;
+    let answers = solve(args.day, args.part, &input)?;
+    if args.part.is_some() {
+        for (_, answer) in &answers {
+            println!("{}", answer);
+        }
+    } else {
+        for (part, answer) in &answers {
+            println!("part {}: {}", part, answer);
+        }
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), Error> {
+    let inputs = inputs_dir(&args.inputs);
+    let path = inputs.dir().join(verify::ANSWERS_FILE);
+    let shown = path.display().to_string();
+    let mut answers = match fs::read_to_string(&path) {
+        Ok(text) => verify::Answers::parse(&text).map_err(|e| Error::Answers(shown.clone(), e))?,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => verify::Answers::default(),
+        Err(e) => return Err(Error::Io(shown, e)),
+    };
+    let mut failed = 0;
+    for day in select_days(args.days)? {
+        for check in verify::check_day(&inputs, &answers, day) {
+            println!("{}", check);
+            if !check.is_ok() {
+                failed += 1;
+            }
+            if args.record {
+                if let Some(actual) = check.actual() {
+                    answers.insert(check.day, check.part, actual.into());
+                }
+            }
+        }
+    }
+    if args.record {
+        fs::write(&path, answers.to_string()).map_err(|e| Error::Io(shown.clone(), e))?;
+        println!("recorded answers in {}", shown);
+    } else if failed > 0 {
+        return Err(Error::Unverified(failed));
+    }
+    Ok(())
+}
+
+fn measure(args: MeasureArgs) -> Result<(), Error> {
+    let inputs = inputs_dir(&args.inputs);
+    let mut reports = vec![];
+    for day in select_days(args.days)? {
+        let path = inputs.path(day);
+        let input = inputs.load(day).map_err(|e| Error::Io(path.display().to_string(), e))?;
+        match days::visit(day, measure::Measure { input: &input }) {
+            Some(report) => reports.push(report?),
+            None => return Err(Error::Unsolved(day)),
+        }
+    }
+    if args.json {
+        print!("{}", measure::Json(&reports));
+    } else {
+        print!("{}", measure::Table(&reports));
+    }
+    Ok(())
+}
+
+fn main() {
+    let result = parse_args(env::args().skip(1)).and_then(|cmd| match cmd {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Measure(args) => measure(args),
+    });
+    if let Err(e) = result {
+        eprintln!("aoc17: {}", e);
+        if let Error::Usage(_) = e {
+            eprintln!("{}", USAGE);
+        }
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Result<Args, Error> {
+        match parse_args(s.split_whitespace().map(String::from))? {
+            Command::Run(args) => Ok(args),
+            c => panic!("expected a run command, got {:?}", c),
+        }
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(args("run 5").unwrap(), Args { day: 5, part: None, input: None, inputs: None });
+        assert_eq!(args("run 12 --part 1 --input day12.txt").unwrap(),
+                   Args { day: 12, part: Some(1), input: Some("day12.txt".into()), inputs: None });
+        assert_eq!(args("run 3 --inputs ../cache").unwrap(),
+                   Args { day: 3, part: None, input: None, inputs: Some("../cache".into()) });
+        assert!(args("run 3 --inputs").is_err());
+        assert!(args("run").is_err());
+        assert!(args("run 26").is_err());
+        assert!(args("run 3 --part 3").is_err());
+        assert!(args("walk 3").is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let verify = |s: &str| parse_args(s.split_whitespace().map(String::from));
+        assert_eq!(verify("verify").unwrap(),
+                   Command::Verify(VerifyArgs { days: vec![], record: false, inputs: None }));
+        assert_eq!(verify("verify 3 12 --record --inputs mine").unwrap(),
+                   Command::Verify(VerifyArgs { days: vec![3, 12], record: true, inputs: Some("mine".into()) }));
+        assert!(verify("verify 0").is_err());
+        assert!(verify("verify --part 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_measure() {
+        let measure = |s: &str| parse_args(s.split_whitespace().map(String::from));
+        assert_eq!(measure("measure").unwrap(),
+                   Command::Measure(MeasureArgs { days: vec![], json: false, inputs: None }));
+        assert_eq!(measure("measure 13 15 --json").unwrap(),
+                   Command::Measure(MeasureArgs { days: vec![13, 15], json: true, inputs: None }));
+        assert!(measure("measure --record").is_err());
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(1, Some(1), "91212129\n").unwrap(), vec![(1, "9".to_string())]);
+        assert_eq!(solve(3, Some(2), "800").unwrap(), vec![(2, "806".to_string())]);
+        assert_eq!(solve(11, None, "se,sw,se,sw,sw").unwrap(),
+                   vec![(1, "3".to_string()), (2, "3".to_string())]);
+        match solve(1, None, "12a") {
+            Err(Error::Solution(common::Error::Parse(_))) => {},
+            r => panic!("expected a parse error, got {:?}", r),
+        }
+        match solve(18, Some(1), "") {
+            Err(Error::Unsolved(18)) => {},
+            r => panic!("expected day 18 to be unsolved, got {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use common::{Error, Solution};
+use days::Visit;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// The system allocator, keeping track of how much is live and the most that has been
+pub struct Counting;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            grow(new_size);
+        }
+        new
+    }
+}
+
+fn grow(size: usize) {
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+/// How long one stage of a day took, and how far the heap grew while it ran
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    /// Peak bytes allocated on top of what was already live when the stage started
+    pub peak: usize,
+}
+
+/// Runs `f`, measuring it. The heap is shared, so other threads' allocations count too.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Measurement) {
+    let base = LIVE.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let start = Instant::now();
+    let out = f();
+    let elapsed = start.elapsed();
+    let peak = PEAK.load(Ordering::Relaxed).saturating_sub(base);
+    (out, Measurement { elapsed, peak })
+}
+
+/// Parse, part 1 and part 2 of one day, each measured on its own
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Measurement,
+    pub part1: Measurement,
+    pub part2: Measurement,
+}
+
+impl Report {
+    fn stages(&self) -> [(&'static str, &Measurement); 3] {
+        [("parse", &self.parse), ("part1", &self.part1), ("part2", &self.part2)]
+    }
+}
+
+pub struct Measure<'a> {
+    pub input: &'a str,
+}
+
+impl<'a> Visit for Measure<'a> {
+    type Output = Result<Report, Error>;
+
+    fn visit<S: Solution>(self, day: u8) -> Self::Output {
+        let (parsed, parse) = measure(|| S::parse(self.input));
+        let parsed = parsed?;
+        let (answer, part1) = measure(|| S::part1(&parsed).map(|a| a.to_string()));
+        answer?;
+        let (answer, part2) = measure(|| S::part2(&parsed).map(|a| a.to_string()));
+        answer?;
+        Ok(Report { day, parse, part1, part2 })
+    }
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs() as f64 * 1e3 + f64::from(d.subsec_nanos()) / 1e6
+}
+
+/// The reports as a table, one row per day
+pub struct Table<'a>(pub &'a [Report]);
+
+impl<'a> fmt::Display for Table<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "day  {:>12} {:>12}  {:>12} {:>12}  {:>12} {:>12}",
+                 "parse ms", "parse peak", "part1 ms", "part1 peak", "part2 ms", "part2 peak")?;
+        for report in self.0 {
+            write!(f, "{:3}", report.day)?;
+            for &(_, m) in &report.stages() {
+                write!(f, "  {:12.3} {:12}", millis(m.elapsed), m.peak)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The reports as a JSON array, times in milliseconds and peaks in bytes
+pub struct Json<'a>(pub &'a [Report]);
+
+impl<'a> fmt::Display for Json<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, report) in self.0.iter().enumerate() {
+            write!(f, "{}\n  {{\"day\": {}", if i == 0 { "" } else { "," }, report.day)?;
+            for &(name, m) in &report.stages() {
+                write!(f, ", \"{}\": {{\"ms\": {:.3}, \"peak_bytes\": {}}}", name, millis(m.elapsed), m.peak)?;
+            }
+            write!(f, "}}")?;
+        }
+        writeln!(f, "\n]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_allocations() {
+        let (v, m) = measure(|| vec![1u8; 1 << 20]);
+        assert_eq!(v.len(), 1 << 20);
+        assert!(m.peak >= 1 << 20, "peak was {}", m.peak);
+    }
+
+    #[test]
+    fn json_report() {
+        let m = |ms, peak| Measurement { elapsed: Duration::from_millis(ms), peak };
+        let reports = [Report { day: 3, parse: m(0, 0), part1: m(12, 64), part2: m(1500, 2048) }];
+        assert_eq!(Json(&reports).to_string(),
+                   "[\n  {\"day\": 3, \"parse\": {\"ms\": 0.000, \"peak_bytes\": 0}, \
+                    \"part1\": {\"ms\": 12.000, \"peak_bytes\": 64}, \
+                    \"part2\": {\"ms\": 1500.000, \"peak_bytes\": 2048}}\n]\n");
+        assert_eq!(Json(&[]).to_string(), "[\n]\n");
+    }
+}
@@ -0,0 +1,151 @@
+use common::{Inputs, ParseError};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Where the expected answers live, alongside the inputs they belong to
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Expected answers, keyed by day and part
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+impl Answers {
+    /// Reads `<day> <part> <answer>` lines, skipping blank lines and `#` comments
+    pub fn parse(input: &str) -> Result<Answers, ParseError> {
+        let mut answers = Answers::default();
+        for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty() && !l.starts_with('#')) {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let day = match words[0].parse() {
+                Ok(d) if (1..=25).contains(&d) => d,
+                _ => return Err(ParseError::at(input, words[0], "a day between 1 and 25")),
+            };
+            let part = match words.get(1) {
+                Some(&"1") => 1,
+                Some(&"2") => 2,
+                Some(p) => return Err(ParseError::at(input, p, "part 1 or 2")),
+                None => return Err(ParseError::at(input, &line[line.len()..], "part 1 or 2")),
+            };
+            match words.len() {
+                3 => answers.insert(day, part, words[2].into()),
+                2 => return Err(ParseError::at(input, &line[line.len()..], "an answer")),
+                _ => return Err(ParseError::at(input, words[3], "the end of the line")),
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(|a| &a[..])
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: String) {
+        self.0.insert((day, part), answer);
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# <day> <part> <answer>, checked by `aoc17 verify`")?;
+        for (&(day, part), answer) in &self.0 {
+            writeln!(f, "{} {} {}", day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Match(String),
+    Mismatch { expected: String, actual: String },
+    Unrecorded(String),
+    Failed(String),
+}
+
+/// How one part of one day fared against the expected answers
+#[derive(Debug, PartialEq)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn is_ok(&self) -> bool {
+        matches!(self.outcome, Outcome::Match(_))
+    }
+
+    /// The answer the solver gave, if it gave one
+    pub fn actual(&self) -> Option<&str> {
+        match self.outcome {
+            Outcome::Match(ref a) | Outcome::Unrecorded(ref a) => Some(a),
+            Outcome::Mismatch { ref actual, .. } => Some(actual),
+            Outcome::Failed(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {:2} part {}: ", self.day, self.part)?;
+        match self.outcome {
+            Outcome::Match(ref a) => write!(f, "ok ({})", a),
+            Outcome::Mismatch { ref expected, ref actual } => write!(f, "MISMATCH expected {}, got {}", expected, actual),
+            Outcome::Unrecorded(ref a) => write!(f, "no recorded answer, got {}", a),
+            Outcome::Failed(ref e) => write!(f, "FAILED {}", e),
+        }
+    }
+}
+
+/// Solves both parts of `day` from its input file and compares them against `answers`
+pub fn check_day(inputs: &Inputs, answers: &Answers, day: u8) -> Vec<Check> {
+    let input = inputs.load(day)
+                      .map_err(|e| format!("could not read {}: {}", inputs.path(day).display(), e));
+    (1..3).map(|part| {
+        let actual = match input {
+            Ok(ref input) => super::solve(day, Some(part), input).map(|mut a| a.remove(0).1)
+                                                                 .map_err(|e| e.to_string()),
+            Err(ref e) => Err(e.clone()),
+        };
+        let outcome = match (actual, answers.get(day, part)) {
+            (Err(e), _) => Outcome::Failed(e),
+            (Ok(actual), None) => Outcome::Unrecorded(actual),
+            (Ok(actual), Some(expected)) if actual == expected => Outcome::Match(actual),
+            (Ok(actual), Some(expected)) => Outcome::Mismatch { expected: expected.into(), actual },
+        };
+        Check { day, part, outcome }
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use days;
+    use std::fs;
+
+    #[test]
+    fn answers_round_trip() {
+        let text = "# comment\n\n3 2 806\n1 1 9\n10 2 a2582a3a0e66e6e86e3812dcb672a272\n";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(answers.get(1, 1), Some("9"));
+        assert_eq!(answers.get(1, 2), None);
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+
+        assert_eq!(Answers::parse("1 1 9\n26 1 3").unwrap_err(),
+                   ParseError::new(2, 1, "26", "a day between 1 and 25"));
+        assert_eq!(Answers::parse("1 3 9").unwrap_err(), ParseError::new(1, 3, "3", "part 1 or 2"));
+        assert_eq!(Answers::parse("1 1").unwrap_err(), ParseError::new(1, 4, "", "an answer"));
+    }
+
+    #[test]
+    fn recorded_answers_match() {
+        let inputs = Inputs::locate();
+        let text = fs::read_to_string(inputs.dir().join(ANSWERS_FILE)).expect("Could not read answers");
+        let answers = Answers::parse(&text).expect("Could not parse answers");
+        let failures = days::solved().into_iter()
+                                     .flat_map(|day| check_day(&inputs, &answers, day))
+                                     .filter(|c| !c.is_ok())
+                                     .map(|c| c.to_string())
+                                     .collect::<Vec<_>>();
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,207 @@
+use std::env;
+use std::fmt;
+
+/// Seeds the differential checks, to replay a failure or look for new ones
+pub const SEED_VAR: &str = "AOC17_SEED";
+
+const DEFAULT_SEED: u64 = 20171225;
+
+/// A small xorshift generator, enough to make up puzzle inputs reproducibly
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // xorshift gets stuck on 0
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `0..n`
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "nothing is below 0");
+        self.next_u64() % n
+    }
+
+    /// A number in `lo..=hi`
+    pub fn between(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "{} is more than {}", lo, hi);
+        lo + self.below((hi - lo) as u64 + 1) as i64
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+
+    /// `len` random lowercase letters
+    pub fn letters(&mut self, len: usize) -> String {
+        (0..len).map(|_| (b'a' + self.below(26) as u8) as char).collect()
+    }
+}
+
+/// Smaller versions of a value, tried in order when cutting a failing input down
+pub trait Shrink: Sized {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_unsigned {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<$t> {
+                let mut smaller = vec![];
+                for &n in &[0, *self / 2, self.saturating_sub(1)] {
+                    if n < *self && !smaller.contains(&n) {
+                        smaller.push(n);
+                    }
+                }
+                smaller
+            }
+        }
+    )*}
+}
+
+shrink_unsigned!(u8, u16, u32, u64, usize);
+
+// towards zero from either side
+macro_rules! shrink_signed {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<$t> {
+                let mut smaller = vec![];
+                for &n in &[0, *self / 2, *self - self.signum()] {
+                    if n.abs() < self.abs() && !smaller.contains(&n) {
+                        smaller.push(n);
+                    }
+                }
+                smaller
+            }
+        }
+    )*}
+}
+
+shrink_signed!(i8, i16, i32, i64, isize);
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<(A, B)> {
+        let firsts = self.0.shrink().into_iter().map(|a| (a, self.1.clone()));
+        let seconds = self.1.shrink().into_iter().map(|b| (self.0.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Vec<T>> {
+        let mut smaller = vec![];
+        for i in 0..self.len() {
+            let mut v = self.clone();
+            v.remove(i);
+            smaller.push(v);
+        }
+        for (i, item) in self.iter().enumerate() {
+            for s in item.shrink() {
+                let mut v = self.clone();
+                v[i] = s;
+                smaller.push(v);
+            }
+        }
+        smaller
+    }
+}
+
+/// Runs a naive and a fast implementation of the same thing on made up inputs, panicking with
+/// the smallest input they disagree on
+#[derive(Debug, Clone)]
+pub struct Differential {
+    pub cases: usize,
+    pub seed: u64,
+}
+
+impl Differential {
+    /// Uses `$AOC17_SEED` if it's set, otherwise the same seed every run
+    pub fn new(cases: usize) -> Differential {
+        let seed = env::var(SEED_VAR).ok().and_then(|s| s.parse().ok()).unwrap_or(DEFAULT_SEED);
+        Differential { cases, seed }
+    }
+
+    pub fn run<T, R, G, N, F>(&self, mut gen: G, naive: N, fast: F)
+        where T: Shrink + Clone + fmt::Debug,
+              R: PartialEq + fmt::Debug,
+              G: FnMut(&mut Rng) -> T,
+              N: Fn(&T) -> R,
+              F: Fn(&T) -> R
+    {
+        let mut rng = Rng::new(self.seed);
+        let agree = |input: &T| naive(input) == fast(input);
+        for _ in 0..self.cases {
+            let original = gen(&mut rng);
+            if agree(&original) {
+                continue;
+            }
+            let mut input = original.clone();
+            while let Some(smaller) = input.shrink().into_iter().find(|s| !agree(s)) {
+                input = smaller;
+            }
+            panic!("naive and fast disagree on {:?} (shrunk from {:?}, {}={}): naive gave {:?}, fast gave {:?}",
+                   input, original, SEED_VAR, self.seed, naive(&input), fast(&input));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn shrinks() {
+        assert_eq!(10u32.shrink(), vec![0, 5, 9]);
+        assert_eq!(1u8.shrink(), vec![0]);
+        assert!(0usize.shrink().is_empty());
+        assert_eq!((-10i64).shrink(), vec![0, -5, -9]);
+        assert_eq!(3i32.shrink(), vec![0, 1, 2]);
+        assert_eq!(vec![2u8].shrink(), vec![vec![], vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..100 {
+            assert!((-3..=3).contains(&rng.between(-3, 3)));
+            assert!("xyz".contains(*rng.pick(&['x', 'y', 'z'])));
+        }
+        assert_eq!(rng.between(5, 5), 5);
+        let mut v = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut v);
+        v.sort();
+        assert_eq!(v, (0..10).collect::<Vec<_>>());
+        assert!(rng.letters(8).chars().all(|c| c.is_ascii_lowercase()));
+    }
+
+    #[test]
+    fn agreeing() {
+        Differential::new(50).run(|rng| rng.below(1000), |&n| n * 2, |&n| n << 1);
+    }
+
+    #[test]
+    fn finds_the_smallest_disagreement() {
+        let result = panic::catch_unwind(|| {
+            Differential::new(50).run(|rng| vec![rng.below(1000), rng.below(1000)],
+                                      |v| v.iter().sum::<u64>(),
+                                      |v| v.iter().filter(|&&n| n < 300).sum::<u64>());
+        });
+        let message = result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.starts_with("naive and fast disagree on [300]"), "{}", message);
+    }
+}
@@ -0,0 +1,74 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Points at the inputs directory to use, instead of searching for one
+pub const INPUTS_VAR: &str = "AOC17_INPUTS";
+
+/// A directory of puzzle inputs, one `dayNN.txt` file per day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Inputs {
+        Inputs {
+            dir: dir.into(),
+        }
+    }
+
+    /// Uses `$AOC17_INPUTS` if it's set, otherwise the nearest `inputs` directory in or above the
+    /// current one, falling back to `./inputs`
+    pub fn locate() -> Inputs {
+        if let Some(dir) = env::var_os(INPUTS_VAR) {
+            return Inputs::new(dir);
+        }
+        env::current_dir().ok()
+                          .and_then(|cwd| Inputs::search(&cwd))
+                          .unwrap_or_else(|| Inputs::new("inputs"))
+    }
+
+    /// Finds the nearest `inputs` directory in `start` or one of its ancestors
+    pub fn search(start: &Path) -> Option<Inputs> {
+        start.ancestors()
+             .map(|d| d.join("inputs"))
+             .find(|d| d.is_dir())
+             .map(Inputs::new)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{:02}.txt", day))
+    }
+
+    pub fn load(&self, day: u8) -> io::Result<String> {
+        fs::read_to_string(self.path(day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_upwards() {
+        let root = env::temp_dir().join(format!("aoc17-inputs-{}", std::process::id()));
+        let nested = root.join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        fs::write(root.join("inputs").join("day03.txt"), "347992\n").unwrap();
+
+        let inputs = Inputs::search(&nested).unwrap();
+        assert_eq!(inputs.dir(), root.join("inputs").as_path());
+        assert_eq!(inputs.path(3), root.join("inputs").join("day03.txt"));
+        assert_eq!(inputs.load(3).unwrap(), "347992\n");
+        assert!(inputs.load(4).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
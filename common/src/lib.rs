@@ -0,0 +1,121 @@
+use std::error;
+use std::fmt;
+
+pub use check::{Differential, Rng, Shrink, SEED_VAR};
+pub use inputs::{Inputs, INPUTS_VAR};
+pub use observe::{Observer, Print, Silent};
+
+mod check;
+mod inputs;
+mod observe;
+
+/// Where an input stopped making sense, and what was found there
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The offending token, empty if the input ran out
+    pub token: String,
+    /// What should have been there instead
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new<S: Into<String>>(line: usize, column: usize, token: &str, expected: S) -> ParseError {
+        ParseError {
+            line,
+            column,
+            token: token.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Builds an error for `token`, which must be a slice of `input`, working out its line and
+    /// column from where it sits in `input`
+    pub fn at<S: Into<String>>(input: &str, token: &str, expected: S) -> ParseError {
+        let offset = (token.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        debug_assert!(offset <= input.len(), "token {:?} is not part of the input", token);
+        let before = &input[..offset.min(input.len())];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        ParseError::new(before.matches('\n').count() + 1,
+                        before[line_start..].chars().count() + 1,
+                        token,
+                        expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+        if self.token.is_empty() {
+            write!(f, ", found nothing")
+        } else {
+            write!(f, ", found `{}`", self.token)
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Why a puzzle input didn't produce an answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The input couldn't be parsed
+    Parse(ParseError),
+    /// The input parsed fine, but there is no answer for it
+    NoAnswer(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Parse(ref e) => write!(f, "parse error: {}", e),
+            Error::NoAnswer(ref msg) => write!(f, "no answer: {}", msg),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Error {
+        Error::Parse(e)
+    }
+}
+
+/// A day's puzzle: parse the input once, then answer both parts from it
+pub trait Solution {
+    type Parsed;
+    type Part1: fmt::Display;
+    type Part2: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error>;
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Part1, Error>;
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Part2, Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "1 2 3\n4 five 6\n";
+        let err = ParseError::at(input, &input[8..12], "a number");
+        assert_eq!(err, ParseError::new(2, 3, "five", "a number"));
+        assert_eq!(err.to_string(), "line 2, column 3: expected a number, found `five`");
+
+        let err = ParseError::at(input, &input[5..5], "another number");
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.to_string(), "line 1, column 6: expected another number, found nothing");
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let input = "héllo wörld";
+        let err = ParseError::at(input, &input[7..], "something else");
+        assert_eq!((err.line, err.column, &err.token[..]), (1, 7, "wörld"));
+    }
+}
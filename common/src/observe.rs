@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// Watches a solver work through its steps. Solvers stay quiet unless they're handed one.
+pub trait Observer<E: ?Sized> {
+    fn observe(&mut self, event: &E);
+}
+
+/// Ignores every event
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Silent;
+
+/// Prints every event to stdout on its own line
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Print;
+
+impl<E: ?Sized> Observer<E> for Silent {
+    fn observe(&mut self, _: &E) {}
+}
+
+impl<E: ?Sized + fmt::Debug> Observer<E> for Print {
+    fn observe(&mut self, event: &E) {
+        println!("{:?}", event);
+    }
+}
+
+impl<E: ?Sized, F: FnMut(&E)> Observer<E> for F {
+    fn observe(&mut self, event: &E) {
+        self(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_down<O: Observer<u32>>(from: u32, observer: &mut O) {
+        for i in (0..from).rev() {
+            observer.observe(&i);
+        }
+    }
+
+    #[test]
+    fn closures_observe() {
+        let mut seen = vec![];
+        count_down(3, &mut |&i: &u32| seen.push(i));
+        assert_eq!(seen, vec![2, 1, 0]);
+        count_down(3, &mut Silent);
+    }
+}
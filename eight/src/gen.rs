@@ -0,0 +1,62 @@
+use common::Rng;
+
+/// How long a program to make, and how many registers it touches
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub instructions: usize,
+    pub registers: usize,
+    /// Amounts and comparisons run from `-max_value` to `max_value`
+    pub max_value: i64,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { instructions: 1000, registers: 26, max_value: 1000 }
+    }
+}
+
+const KEYWORDS: [&str; 3] = ["if", "inc", "dec"];
+const COMPARISONS: [&str; 6] = ["==", "!=", "<", ">", "<=", ">="];
+
+fn register_names(rng: &mut Rng, count: usize) -> Vec<String> {
+    let mut names = vec![];
+    while names.len() < count.max(1) {
+        let len = rng.below(3) as usize + 1;
+        let name = rng.letters(len);
+        if !KEYWORDS.contains(&&name[..]) && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    let registers = register_names(rng, size.registers);
+    let max = size.max_value.abs();
+    (0..size.instructions).map(|_| {
+        format!("{} {} {} if {} {} {}\n",
+                rng.pick(&registers),
+                rng.pick(&["inc", "dec"]),
+                rng.between(-max, max),
+                rng.pick(&registers),
+                rng.pick(&COMPARISONS),
+                rng.between(-max, max))
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(8);
+        for n in 1..20 {
+            let program = Day::parse(&input(&mut rng, &Size { instructions: n, registers: n, max_value: 10 })).unwrap();
+            assert_eq!(program.0.len(), n);
+            assert!(Day::part1(&program).unwrap() <= Day::part2(&program).unwrap());
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use common::Rng;
+
+/// How long a path to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub steps: usize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { steps: 8000 }
+    }
+}
+
+const DIRECTIONS: [&str; 6] = ["n", "ne", "se", "s", "sw", "nw"];
+
+pub fn directions(rng: &mut Rng, size: &Size) -> Vec<&'static str> {
+    (0..size.steps.max(1)).map(|_| *rng.pick(&DIRECTIONS)).collect()
+}
+
+pub fn format(directions: &[&str]) -> String {
+    directions.join(",") + "\n"
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&directions(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(11);
+        for n in 1..30 {
+            let path = Day::parse(&input(&mut rng, &Size { steps: n })).unwrap();
+            assert_eq!(path.0.len(), n);
+            let (distance, furthest) = (Day::part1(&path).unwrap(), Day::part2(&path).unwrap());
+            assert!(distance <= furthest && furthest <= n);
+        }
+    }
+}
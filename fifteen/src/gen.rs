@@ -0,0 +1,43 @@
+use common::Rng;
+
+/// How big the starting values can get
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    /// Kept below 2147483647, which the generators work modulo
+    pub max_start: u64,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { max_start: 1000 }
+    }
+}
+
+pub fn starts(rng: &mut Rng, size: &Size) -> (u64, u64) {
+    let max = size.max_start.clamp(1, 2_147_483_646);
+    (rng.below(max) + 1, rng.below(max) + 1)
+}
+
+pub fn format((a, b): (u64, u64)) -> String {
+    format!("Generator A starts with {}\nGenerator B starts with {}\n", a, b)
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(starts(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(15);
+        for n in 1..30 {
+            let starts = starts(&mut rng, &Size { max_start: n });
+            assert_eq!(Day::parse(&format(starts)).unwrap(), starts);
+        }
+    }
+}
@@ -1,3 +1,9 @@
+extern crate common;
+
+use common::{Error, ParseError, Solution};
+
+pub mod gen;
+
 const NUM: u64 = 2147483647;
 const A_FACTOR: u64 = 16807;
 const B_FACTOR: u64 = 48271;
@@ -13,8 +19,8 @@ impl Generator {
     fn new(value: u64, factor: u64, criteria: u64) -> Generator {
         Generator {
             valu: value,
-            factor: factor,
-            criteria: criteria,
+            factor,
+            criteria,
         }
     }
 }
@@ -26,7 +32,7 @@ impl Iterator for Generator {
         Some(loop {
             let interm = self.valu * self.factor;
             self.valu = interm % NUM;
-            if self.valu % self.criteria == 0 {
+            if self.valu.is_multiple_of(self.criteria) {
                 break self.valu
             }
         })
@@ -37,12 +43,42 @@ fn low16bits(num: u64) -> u64 {
     num & 0xffff
 }
 
-fn run(astart: u64, acrit: u64, bstart: u64, bcrit: u64, rounds: usize) -> usize {
+pub fn run(astart: u64, acrit: u64, bstart: u64, bcrit: u64, rounds: usize) -> usize {
     let a = Generator::new(astart, A_FACTOR, acrit);
     let b = Generator::new(bstart, B_FACTOR, bcrit);
     let iter = a.zip(b);
-    let count = iter.take(rounds).map(|(a, b)| (low16bits(a), low16bits(b))).filter(|&(a, b)| a == b).count();
-    count
+    iter.take(rounds).map(|(a, b)| (low16bits(a), low16bits(b))).filter(|&(a, b)| a == b).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (u64, u64);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    // inputs look like "Generator A starts with 591", so take the last word of each line
+    fn parse(input: &str) -> Result<(u64, u64), Error> {
+        let words = input.lines()
+                         .filter_map(|l| l.split_whitespace().last())
+                         .collect::<Vec<_>>();
+        let starts = words.iter()
+                          .map(|s| s.parse().map_err(|_| ParseError::at(input, s, "a starting value")))
+                          .collect::<Result<Vec<u64>, _>>()?;
+        match starts[..] {
+            [a, b] => Ok((a, b)),
+            [_] | [] => Err(ParseError::at(input, &input[input.len()..], "2 generators").into()),
+            _ => Err(ParseError::at(input, words[2], "only 2 generators").into()),
+        }
+    }
+
+    fn part1(&(a, b): &(u64, u64)) -> Result<usize, Error> {
+        Ok(run(a, 1, b, 1, 40_000_000))
+    }
+
+    fn part2(&(a, b): &(u64, u64)) -> Result<usize, Error> {
+        Ok(run(a, 4, b, 8, 5_000_000))
+    }
 }
 
 #[cfg(test)]
@@ -52,10 +88,11 @@ mod tests {
     #[test]
     fn it_works() {
         assert_eq!(run(65, 4, 8921, 8, 5_000_000), 309);
-    }
-
-    #[test]
-    fn answer() {
-        println!("answer is {}", run(591, 4, 393, 8, 5_000_000));
+        assert_eq!(run(65, 1, 8921, 1, 5), 1);
+        assert_eq!(Day::parse("Generator A starts with 65\nGenerator B starts with 8921").unwrap(), (65, 8921));
+        assert_eq!(Day::parse("Generator A starts with 65\nGenerator B starts with B").unwrap_err(),
+                   Error::Parse(ParseError::new(2, 25, "B", "a starting value")));
+        assert_eq!(Day::parse("Generator A starts with 65\n").unwrap_err(),
+                   Error::Parse(ParseError::new(2, 1, "", "2 generators")));
     }
 }
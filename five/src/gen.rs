@@ -0,0 +1,46 @@
+use common::Rng;
+
+/// How long a jump list to make, and how far each jump can go
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub jumps: usize,
+    /// Offsets run from `-max_offset` to `max_offset`
+    pub max_offset: isize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { jumps: 1000, max_offset: 1000 }
+    }
+}
+
+pub fn jumps(rng: &mut Rng, size: &Size) -> Vec<isize> {
+    let max = size.max_offset.abs() as i64;
+    (0..size.jumps).map(|_| rng.between(-max, max) as isize).collect()
+}
+
+pub fn format(jumps: &[isize]) -> String {
+    jumps.iter().map(|j| format!("{}\n", j)).collect()
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&jumps(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(5);
+        for n in 1..30 {
+            let jumps = jumps(&mut rng, &Size { jumps: n, max_offset: 3 });
+            let parsed = Day::parse(&format(&jumps)).unwrap();
+            assert_eq!(parsed, jumps);
+            Day::part1(&parsed).unwrap();
+        }
+    }
+}
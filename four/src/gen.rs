@@ -0,0 +1,54 @@
+use common::Rng;
+
+/// How many passphrases to make, and what they look like
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub lines: usize,
+    pub max_words: usize,
+    pub max_word_len: usize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { lines: 512, max_words: 12, max_word_len: 7 }
+    }
+}
+
+// words are short enough that repeats and anagrams turn up, so both parts have work to do
+pub fn passphrases(rng: &mut Rng, size: &Size) -> Vec<String> {
+    (0..size.lines).map(|_| {
+        let words = rng.below(size.max_words.max(1) as u64) + 1;
+        (0..words).map(|_| {
+                       let len = rng.below(size.max_word_len.max(1) as u64) as usize + 1;
+                       rng.letters(len)
+                   })
+                   .collect::<Vec<_>>()
+                   .join(" ")
+    }).collect()
+}
+
+pub fn format(passphrases: &[String]) -> String {
+    passphrases.iter().map(|p| format!("{}\n", p)).collect()
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&passphrases(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(4);
+        for n in 1..20 {
+            let passphrases = passphrases(&mut rng, &Size { lines: n, max_words: n, max_word_len: 3 });
+            let parsed = Day::parse(&format(&passphrases)).unwrap();
+            assert_eq!(parsed, passphrases);
+            assert!(Day::part2(&parsed).unwrap() <= Day::part1(&parsed).unwrap());
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use common::Rng;
+
+/// How long a key to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub key_len: usize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { key_len: 8 }
+    }
+}
+
+pub fn key(rng: &mut Rng, size: &Size) -> String {
+    rng.letters(size.key_len.max(1))
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    key(rng, size) + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(14);
+        for n in 1..20 {
+            let key = key(&mut rng, &Size { key_len: n });
+            assert_eq!(Day::parse(&format!("{}\n", key)).unwrap(), key);
+        }
+    }
+}
@@ -58,8 +58,8 @@ impl List {
 }
 
 fn get_lengths(input: &str) -> Vec<usize> {
-    let mut lengths = input.chars()
-        .map(|c| c as u32 as usize)
+    let mut lengths = input.bytes()
+        .map(usize::from)
         .collect::<Vec<usize>>();
     lengths.extend_from_slice(&[17, 31, 73, 47, 23]);
     lengths
@@ -69,7 +69,7 @@ fn run_one(lengths: &[usize], list: &mut List) -> i64 {
     for len in lengths {
         list.step(*len).expect("Couldnt do step");
         list.curr += len + list.skip_size;
-        list.curr = list.curr % list.list.len();
+        list.curr %= list.list.len();
         list.skip_size += 1;
     }
     list.list[0] * list.list[1]
@@ -1,5 +1,8 @@
 #![allow(dead_code)]
 
+extern crate common;
+
+use common::{Error, Observer, ParseError, Silent, Solution};
 use std::cell::RefCell;
 use std::fmt;
 use std::convert::From;
@@ -7,6 +10,7 @@ use std::ops::Index;
 
 use knothash::KnotHash;
 
+pub mod gen;
 mod knothash;
 
 struct GridCell {
@@ -99,7 +103,7 @@ impl<'a> From<&'a str> for BinGrid {
         for i in 0..128 {
             let inp = format!("{}-{}", input, i);
             let hash = KnotHash::from(&inp[..]);
-            for cell in hash.to_bin_str().chars().map(|c| GridCell::from(c)) {
+            for cell in hash.to_bin_str().chars().map(GridCell::from) {
                 accum.push(cell);
             }
         }
@@ -113,7 +117,7 @@ impl<'a> From<&'a str> for BinGrid {
 
 impl BinGrid {
     fn rows(&self) -> usize {
-        (self.data.len() / self.width) as usize
+        self.data.len() / self.width
     }
 
     fn cols(&self) -> usize {
@@ -189,41 +193,53 @@ impl BinGrid {
         &mut self.data[idx]
     }
 
-    fn mark_group(&self, x: usize, y: usize, group: usize) -> bool {
+    fn mark_group<O: Observer<Mark>>(&self, x: usize, y: usize, group: usize, observer: &mut O) -> bool {
         let cell = self.get(x, y);
         if !cell.is_set() {
             return false;
         }
         if cell.has_group() {
-            println!("cell({}, {}) already set to {}", x, y, cell.group());
+            observer.observe(&Mark::AlreadySet { x, y, group: cell.group() as usize });
             return false;
         }
         {
-            println!("setting cell({}, {}) to {}", x, y, group);
+            observer.observe(&Mark::Set { x, y, group });
             cell.set_group(group);
         }
         if let Some(coords) = self.left(x, y) {
-            self.mark_group(coords.0, coords.1, group);
+            self.mark_group(coords.0, coords.1, group, observer);
         }
         if let Some(coords) = self.right(x, y) {
-            self.mark_group(coords.0, coords.1, group);
+            self.mark_group(coords.0, coords.1, group, observer);
         }
         if let Some(coords) = self.top(x, y) {
-            self.mark_group(coords.0, coords.1, group);
+            self.mark_group(coords.0, coords.1, group, observer);
         }
         if let Some(coords) = self.bottom(x, y) {
-            self.mark_group(coords.0, coords.1, group);
+            self.mark_group(coords.0, coords.1, group, observer);
         }
         true
     }
 }
 
-fn run(input: &str) -> usize {
+/// What happens to a used square while its region is being flood filled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mark {
+    Set { x: usize, y: usize, group: usize },
+    AlreadySet { x: usize, y: usize, group: usize },
+}
+
+pub fn run(input: &str) -> usize {
+    run_with(input, &mut Silent)
+}
+
+/// Like `run`, showing `observer` every square as it's marked with its region
+pub fn run_with<O: Observer<Mark>>(input: &str, observer: &mut O) -> usize {
     let mut group = 0;
     let grid = BinGrid::from(input);
     for y in 0..grid.rows() {
         for x in 0..grid.cols() {
-            if grid.mark_group(x, y, group) {
+            if grid.mark_group(x, y, group, observer) {
                 group += 1;
             }
         }
@@ -231,13 +247,57 @@ fn run(input: &str) -> usize {
     group
 }
 
+// part two hashes the input a byte at a time, which only matches the puzzle for ASCII
+fn ascii(input: &str) -> Result<(), ParseError> {
+    match input.char_indices().find(|&(_, c)| !c.is_ascii()) {
+        Some((i, c)) => Err(ParseError::at(input, &input[i..i + c.len_utf8()], "an ASCII character")),
+        None => Ok(()),
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<String, Error> {
+        ascii(input)?;
+        Ok(input.trim().into())
+    }
+
+    fn part1(key: &String) -> Result<usize, Error> {
+        let grid = BinGrid::from(&key[..]);
+        Ok(grid.data.iter().filter(|c| c.is_set()).count())
+    }
+
+    fn part2(key: &String) -> Result<usize, Error> {
+        Ok(run(key))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_input() {
-        let input = "nbysizxe";
-        println!("answer is {}", run(input));
+    fn it_works() {
+        let key = Day::parse("flqrgnkx").unwrap();
+        assert_eq!(Day::part1(&key).unwrap(), 8108);
+        assert_eq!(Day::part2(&key).unwrap(), 1242);
+    }
+
+    #[test]
+    fn not_ascii() {
+        assert_eq!(Day::parse(" flq€").unwrap_err(),
+                   Error::Parse(ParseError::new(1, 5, "€", "an ASCII character")));
+    }
+
+    #[test]
+    fn observed_marks() {
+        let mut set = 0;
+        run_with("flqrgnkx", &mut |m: &Mark| if let Mark::Set { .. } = *m { set += 1 });
+        assert_eq!(set, 8108);
     }
 }
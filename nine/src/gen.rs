@@ -0,0 +1,87 @@
+use common::Rng;
+
+/// How deep and wide a stream to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub max_depth: usize,
+    /// The most groups and garbage any one group holds
+    pub max_children: usize,
+    pub max_garbage: usize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { max_depth: 12, max_children: 6, max_garbage: 20 }
+    }
+}
+
+/// A made up stream, with what part 1 and part 2 should make of it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stream {
+    pub text: String,
+    pub score: i32,
+    pub garbage: i32,
+}
+
+// anything but `>` can sit in garbage as is, and `!` cancels whatever follows it
+const GARBAGE: &[u8] = b"abcdeiou{}<,'\"";
+
+fn garbage(rng: &mut Rng, size: &Size, stream: &mut Stream) {
+    stream.text.push('<');
+    for _ in 0..rng.below(size.max_garbage as u64 + 1) {
+        if rng.below(5) == 0 {
+            stream.text.push('!');
+            stream.text.push(*rng.pick(b"!>a<") as char);
+        } else {
+            stream.text.push(*rng.pick(GARBAGE) as char);
+            stream.garbage += 1;
+        }
+    }
+    stream.text.push('>');
+}
+
+fn group(rng: &mut Rng, size: &Size, depth: usize, stream: &mut Stream) {
+    stream.text.push('{');
+    stream.score += depth as i32;
+    let children = if depth > size.max_depth { 0 } else { rng.below(size.max_children as u64 + 1) };
+    for i in 0..children {
+        if i > 0 {
+            stream.text.push(',');
+        }
+        if rng.below(2) == 0 {
+            group(rng, size, depth + 1, stream);
+        } else {
+            garbage(rng, size, stream);
+        }
+    }
+    stream.text.push('}');
+}
+
+pub fn stream(rng: &mut Rng, size: &Size) -> Stream {
+    let mut stream = Stream { text: String::new(), score: 0, garbage: 0 };
+    group(rng, size, 1, &mut stream);
+    stream.text.push('\n');
+    stream
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    stream(rng, size).text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(9);
+        for n in 0..20 {
+            let stream = stream(&mut rng, &Size { max_depth: n, max_children: 3, max_garbage: n });
+            let parsed = Day::parse(&stream.text).unwrap();
+            assert_eq!(Day::part1(&parsed).unwrap(), stream.score, "{}", stream.text);
+            assert_eq!(Day::part2(&parsed).unwrap(), stream.garbage, "{}", stream.text);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use common::Rng;
+
+/// How big a captcha to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    /// Always rounded up to an even number, so part 2 has a halfway point
+    pub digits: usize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { digits: 2000 }
+    }
+}
+
+pub fn digits(rng: &mut Rng, size: &Size) -> Vec<u8> {
+    (0..(size.digits + size.digits % 2)).map(|_| rng.below(10) as u8).collect()
+}
+
+pub fn format(digits: &[u8]) -> String {
+    let mut s = digits.iter().map(|d| d.to_string()).collect::<String>();
+    s.push('\n');
+    s
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&digits(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(1);
+        for n in 1..30 {
+            let digits = digits(&mut rng, &Size { digits: n });
+            assert_eq!(digits.len() % 2, 0);
+            assert_eq!(Day::parse(&format(&digits)).unwrap(), digits);
+        }
+    }
+}
@@ -1,52 +1,349 @@
-fn plus_half(idx: usize, len: usize) -> usize {
-    let half = len / 2;
-    (idx + half) % len
+extern crate common;
+
+use common::{Error, ParseError, Solution};
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+use std::io::{self, Read};
+
+pub mod gen;
+
+/// How far round the circle the digit each one is compared with sits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Offset {
+    /// The very next digit, as in part 1
+    Next,
+    /// The digit halfway round, as in part 2
+    Half,
+    Fixed(usize),
+}
+
+impl Offset {
+    fn places(self, len: usize) -> usize {
+        match self {
+            Offset::Next => 1,
+            Offset::Half => len / 2,
+            Offset::Fixed(n) => n,
+        }
+    }
+}
+
+fn parse_digits(input: &str) -> Result<Vec<u8>, ParseError> {
+    let digits = input.trim();
+    digits.char_indices()
+          .map(|(i, c)| match c.to_digit(10) {
+              Some(d) => Ok(d as u8),
+              None => Err(ParseError::at(input, &digits[i..i + c.len_utf8()], "a digit")),
+          })
+          .collect()
+}
+
+// sum every digit that matches the one `offset` places further round the circle
+fn sum_matching(digits: &[u8], offset: Offset) -> u64 {
+    let len = digits.len();
+    if len == 0 {
+        return 0;
+    }
+    // going all the way round lands back on the same digit
+    let places = offset.places(len) % len;
+    (0..len).filter(|&i| digits[i] == digits[(i + places) % len])
+            .map(|i| u64::from(digits[i]))
+            .sum()
+}
+
+/// Why a stream of digits couldn't be summed
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StreamError::Io(ref e) => write!(f, "could not read digits: {}", e),
+            StreamError::Parse(ref e) => write!(f, "parse error: {}", e),
+        }
+    }
 }
 
-fn get_next(input: &str, cur_idx: usize) -> u64 {
-    let idx = plus_half(cur_idx, input.len());
-    let digit = &input[idx..idx+1];
-    let digit = digit.parse().expect(&format!("Could not parse {:?} as a digit", &digit));
-    digit
+impl error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> StreamError {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> StreamError {
+        StreamError::Parse(e)
+    }
+}
+
+// digits arriving one at a time. With a fixed offset only the first and the latest `places`
+// digits are kept, or every digit if there are no more than `places`; halfway round depends on
+// the length, so that keeps every digit.
+struct Captcha {
+    places: Option<usize>,
+    head: Vec<u8>,
+    window: VecDeque<u8>,
+    len: usize,
+    sum: u64,
+}
+
+impl Captcha {
+    fn new(offset: Offset) -> Captcha {
+        let places = match offset {
+            Offset::Half => None,
+            o => Some(o.places(0)),
+        };
+        Captcha {
+            places,
+            head: vec![],
+            window: VecDeque::new(),
+            len: 0,
+            sum: 0,
+        }
+    }
+
+    fn push(&mut self, digit: u8) {
+        match self.places {
+            None => self.head.push(digit),
+            Some(0) => self.sum += u64::from(digit),
+            // until there are `places` digits the window would only be a second copy of `head`
+            Some(places) if self.len < places => self.head.push(digit),
+            Some(places) => {
+                if self.len == places {
+                    self.window = self.head.iter().cloned().collect();
+                }
+                // the digit `places` back has just met the one it's compared with
+                if self.window.pop_front() == Some(digit) {
+                    self.sum += u64::from(digit);
+                }
+                self.window.push_back(digit);
+            },
+        }
+        self.len += 1;
+    }
+
+    fn finish(self) -> u64 {
+        match self.places {
+            None => sum_matching(&self.head, Offset::Half),
+            Some(0) => self.sum,
+            // it's all in `head`, and may wrap round more than once
+            Some(places) if self.len <= places => sum_matching(&self.head, Offset::Fixed(places)),
+            // the last `places` digits wrap round to the first ones
+            Some(_) => {
+                self.sum + self.window.iter()
+                                      .zip(&self.head)
+                                      .filter(|&(a, b)| a == b)
+                                      .map(|(&a, _)| u64::from(a))
+                                      .sum::<u64>()
+            },
+        }
+    }
 }
 
-pub fn calculate_sum(input: &str) -> u64 {
-    let mut numbers: Vec<u64> = Vec::with_capacity(input.len() / 2);
-    for i in 0..input.len() {
-        let digit = &input[i..i+1];
-        let digit = digit.parse().expect(&format!("Could not parse {:?} as a digit", &digit));
-        let next_digit = get_next(input, i);
-        if digit == next_digit {
-            numbers.push(digit);
+/// Like `captcha_sum`, reading the digits from `reader` a chunk at a time. Surrounding whitespace
+/// is skipped, as it is for `captcha_sum`.
+///
+/// `Offset::Next` needs next to no memory, and `Offset::Fixed(n)` holds on to at most `2 * n`
+/// digits. `Offset::Half` can't know where halfway is until the stream ends, so it keeps every
+/// digit: a stream of `len` digits needs `len` bytes.
+pub fn captcha_sum_from<R: Read>(mut reader: R, offset: Offset) -> Result<u64, StreamError> {
+    let mut captcha = Captcha::new(offset);
+    let mut buf = [0; 64 * 1024];
+    let (mut line, mut column) = (1, 0);
+    // whitespace after the digits is only fine if nothing but whitespace follows it
+    let mut trailing: Option<ParseError> = None;
+    loop {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        for (i, &b) in buf[..read].iter().enumerate() {
+            // continuation bytes are part of the character before them
+            if b & 0xc0 != 0x80 {
+                column += 1;
+            }
+            if b.is_ascii_digit() {
+                if let Some(e) = trailing {
+                    return Err(e.into());
+                }
+                captcha.push(b - b'0');
+            } else if b.is_ascii_whitespace() {
+                if captcha.len > 0 && trailing.is_none() {
+                    trailing = Some(ParseError::new(line, column, &(b as char).to_string(), "a digit"));
+                }
+            } else {
+                if let Some(e) = trailing {
+                    return Err(e.into());
+                }
+                let width = match b {
+                    0xf0..=0xff => 4,
+                    0xe0..=0xef => 3,
+                    0xc0..=0xdf => 2,
+                    _ => 1,
+                };
+                let token = String::from_utf8_lossy(&buf[i..read.min(i + width)]);
+                return Err(ParseError::new(line, column, &token, "a digit").into());
+            }
+            if b == b'\n' {
+                line += 1;
+                column = 0;
+            }
         }
     }
-    numbers.iter().sum()
+    Ok(captcha.finish())
+}
+
+/// Sums every digit of `input` that matches the one `offset` places further round
+pub fn captcha_sum(input: &str, offset: Offset) -> Result<u64, ParseError> {
+    match captcha_sum_from(input.as_bytes(), offset) {
+        Ok(sum) => Ok(sum),
+        Err(StreamError::Parse(e)) => Err(e),
+        Err(StreamError::Io(e)) => unreachable!("reading from memory failed: {}", e),
+    }
+}
+
+pub fn calculate_sum(input: &str) -> Result<u64, Error> {
+    Ok(captcha_sum(input, Offset::Half)?)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<u8>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u8>, Error> {
+        Ok(parse_digits(input)?)
+    }
+
+    fn part1(digits: &Vec<u8>) -> Result<u64, Error> {
+        Ok(sum_matching(digits, Offset::Next))
+    }
+
+    fn part2(digits: &Vec<u8>) -> Result<u64, Error> {
+        Ok(sum_matching(digits, Offset::Half))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{get_next, calculate_sum};
+    use super::{captcha_sum, captcha_sum_from, calculate_sum, sum_matching, Captcha, Day, Offset};
+    use common::{Differential, Error, ParseError, Rng, Solution};
+    use std::io::{self, Read};
+
+    #[test]
+    fn offsets() {
+        assert_eq!(Offset::Next.places(4), 1);
+        assert_eq!(Offset::Half.places(4), 2);
+        assert_eq!(Offset::Half.places(7), 3);
+        assert_eq!(Offset::Fixed(5).places(4), 5);
+    }
+
+    #[test]
+    fn any_offset() {
+        assert_eq!(captcha_sum("1122", Offset::Next).unwrap(), 3);
+        assert_eq!(captcha_sum("123123", Offset::Half).unwrap(), 12);
+        assert_eq!(captcha_sum("123123", Offset::Fixed(3)).unwrap(), 12);
+        // wrapping all the way round compares each digit with itself
+        assert_eq!(captcha_sum("1234", Offset::Fixed(4)).unwrap(), 10);
+        assert_eq!(captcha_sum("1234", Offset::Fixed(5)).unwrap(), captcha_sum("1234", Offset::Next).unwrap());
+        assert_eq!(captcha_sum("", Offset::Fixed(2)).unwrap(), 0);
+        assert_eq!(captcha_sum("12", Offset::Fixed(usize::MAX)).unwrap(), 0);
+        assert_eq!(captcha_sum("121", Offset::Fixed(usize::MAX)).unwrap(), 4);
+        assert_eq!(captcha_sum("12a", Offset::Next).unwrap_err(), ParseError::new(1, 3, "a", "a digit"));
+    }
+
+    #[test]
+    fn streamed() {
+        let stream = |s: &str, offset| captcha_sum_from(s.as_bytes(), offset).map_err(|e| e.to_string());
+        assert_eq!(stream("\n 91212129\n\n", Offset::Next), Ok(9));
+        assert_eq!(stream("12131415", Offset::Half), Ok(4));
+        assert_eq!(stream("123", Offset::Fixed(7)), Ok(0));
+        assert_eq!(stream("12\n34", Offset::Next),
+                   Err("parse error: line 1, column 3: expected a digit, found `\n`".into()));
+        assert_eq!(stream("\n1é2", Offset::Next),
+                   Err("parse error: line 2, column 2: expected a digit, found `é`".into()));
+    }
+
+    #[test]
+    fn keeps_only_the_window() {
+        let kept = |offset, digits: &[u8]| {
+            let mut captcha = Captcha::new(offset);
+            digits.iter().for_each(|&d| captcha.push(d));
+            (captcha.head.len(), captcha.window.len())
+        };
+        assert_eq!(kept(Offset::Fixed(10), &[1, 2, 3, 4, 5]), (5, 0));
+        assert_eq!(kept(Offset::Fixed(2), &[1, 2, 3, 4, 5]), (2, 2));
+        assert_eq!(kept(Offset::Next, &[1, 2, 3, 4, 5]), (1, 1));
+    }
+
+    // hands out one byte per read
+    struct Trickle<'a>(&'a [u8]);
+
+    impl<'a> Read for Trickle<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.0.len().min(buf.len()).min(1);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
 
     #[test]
-    fn test_get_next() {
-        assert_eq!(get_next("1234", 0), 3);
-        assert_eq!(get_next("1234", 1), 4);
-        assert_eq!(get_next("1234", 2), 1);
-        assert_eq!(get_next("1234", 3), 2);
+    fn streamed_a_byte_at_a_time() {
+        let input = "123425123425\n";
+        for &offset in &[Offset::Next, Offset::Half, Offset::Fixed(5), Offset::Fixed(20)] {
+            assert_eq!(captcha_sum_from(Trickle(input.as_bytes()), offset).unwrap(),
+                       captcha_sum(input, offset).unwrap());
+        }
+    }
+
+    #[test]
+    fn streamed_matches_in_memory() {
+        let digits = |rng: &mut Rng| (0..rng.below(40)).map(|_| rng.below(4) as u8).collect::<Vec<_>>();
+        let offset = |places: usize| match places % 3 {
+            0 => Offset::Next,
+            1 => Offset::Half,
+            _ => Offset::Fixed(places / 3),
+        };
+        Differential::new(300).run(|rng| (rng.below(60) as usize, digits(rng)),
+                                   |&(places, ref d)| sum_matching(d, offset(places)),
+                                   |&(places, ref d)| {
+                                       let text = d.iter().map(|d| d.to_string()).collect::<String>();
+                                       captcha_sum(&text, offset(places)).unwrap()
+                                   });
     }
 
     #[test]
     fn it_works() {
-        assert_eq!(calculate_sum("1212"), 6);
-        assert_eq!(calculate_sum("1221"), 0);
-        assert_eq!(calculate_sum("123425"), 4);
-        assert_eq!(calculate_sum("123123"), 12);
-        assert_eq!(calculate_sum("12131415"), 4);
+        assert_eq!(calculate_sum("1212").unwrap(), 6);
+        assert_eq!(calculate_sum("1221").unwrap(), 0);
+        assert_eq!(calculate_sum("123425").unwrap(), 4);
+        assert_eq!(calculate_sum("123123").unwrap(), 12);
+        assert_eq!(calculate_sum("12131415").unwrap(), 4);
+        assert_eq!(calculate_sum("12x").unwrap_err(), Error::Parse(ParseError::new(1, 3, "x", "a digit")));
+    }
+
+    #[test]
+    fn next_digit() {
+        let sum = |s| Day::part1(&Day::parse(s).unwrap()).unwrap();
+        assert_eq!(sum("1122"), 3);
+        assert_eq!(sum("1111"), 4);
+        assert_eq!(sum("1234"), 0);
+        assert_eq!(sum("91212129"), 9);
     }
 
     #[test]
-    fn advent_of_code_input() {
-        let input = "823936645345581272695677318513459491834641129844393742672553544439126314399846773234845535593355348931499496184839582118817689171948635864427852215325421433717458975771369522138766248225963242168658975326354785415252974294317138511141826226866364555761117178764543435899886711426319675443679829181257496966219435831621565519667989898725836639626681645821714861443141893427672384716732765884844772433374798185955741311116365899659833634237938878181367317218635539667357364295754744829595842962773524584225427969467467611641591834876769829719248136613147351298534885563144114336211961674392912181735773851634298227454157885241769156811787611897349965331474217223461176896643242975397227859696554492996937235423272549348349528559432214521551656971136859972232854126262349381254424597348874447736545722261957871275935756764184378994167427983811716675476257858556464755677478725146588747147857375293675711575747132471727933773512571368467386151966568598964631331428869762151853634362356935751298121849281442128796517663482391226174256395515166361514442624944181255952124524815268864131969151433888721213595267927325759562132732586252438456569556992685896517565257787464673718221817783929691626876446423134331749327322367571432532857235214364221471769481667118117729326429556357572421333798517168997863151927281418238491791975399357393494751913155219862399959646993428921878798119215675548847845477994836744929918954159722827194721564121532315459611433157384994543332773796862165243183378464731546787498174844781781139571984272235872866886275879944921329959736315296733981313643956576956851762149275521949177991988236529475373595217665112434727744235789852852765675189342753695377219374791548554786671473733124951946779531847479755363363288448281622183736545494372344785112312749694167483996738384351293899149136857728545977442763489799693492319549773328626918874718387697878235744154491677922317518952687439655962477734559232755624943644966227973617788182213621899579391324399386146423427262874437992579573858589183571854577861459758534348533553925167947139351819511798829977371215856637215221838924612644785498936263849489519896548811254628976642391428413984281758771868781714266261781359762798";
-        println!("solution is: {}", calculate_sum(&input));
+    fn bad_digit() {
+        assert_eq!(Day::parse("\n1212x12\n").unwrap_err(),
+                   Error::Parse(ParseError::new(2, 5, "x", "a digit")));
     }
 }
@@ -0,0 +1,127 @@
+use common::Rng;
+use std::collections::HashSet;
+
+/// How big a tower to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub max_depth: usize,
+    /// At least 3, so the program with the wrong weight can be picked out
+    pub max_children: usize,
+    /// The most the whole tower weighs
+    pub max_weight: u64,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { max_depth: 6, max_children: 5, max_weight: 1_000_000 }
+    }
+}
+
+/// A made up tower, with what part 1 and part 2 should make of it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tower {
+    pub text: String,
+    pub root: String,
+    pub fixed_weight: u64,
+}
+
+struct Program {
+    name: String,
+    weight: u64,
+    children: Vec<usize>,
+}
+
+struct Builder<'a> {
+    rng: &'a mut Rng,
+    size: &'a Size,
+    names: HashSet<String>,
+    programs: Vec<Program>,
+}
+
+impl<'a> Builder<'a> {
+    fn name(&mut self) -> String {
+        loop {
+            let len = self.rng.below(4) as usize + 4;
+            let name = self.rng.letters(len);
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    // a balanced tower weighing `total` altogether. The root always gets children, so there is
+    // something to unbalance.
+    fn program(&mut self, total: u64, depth: usize) -> usize {
+        let name = self.name();
+        let min_children = if depth == 0 { 3 } else { 2 };
+        let max_children = self.size.max_children.max(3);
+        let kids = self.rng.below((max_children - min_children) as u64 + 1) as usize + min_children;
+        let mut weight = total;
+        let mut children = vec![];
+        if (depth == 0 || depth < self.size.max_depth) && total > kids as u64 {
+            let child_total = self.rng.below((total - 1) / kids as u64) + 1;
+            weight -= kids as u64 * child_total;
+            children = (0..kids).map(|_| self.program(child_total, depth + 1)).collect();
+        }
+        self.programs.push(Program { name, weight, children });
+        self.programs.len() - 1
+    }
+}
+
+pub fn tower(rng: &mut Rng, size: &Size) -> Tower {
+    let max_weight = size.max_weight.max(100);
+    let total = rng.below(max_weight / 2) + max_weight / 2;
+    let mut builder = Builder { rng, size, names: HashSet::new(), programs: vec![] };
+    let root = builder.program(total, 0);
+
+    // climb while there are enough programs side by side to tell which one is wrong
+    let mut wrong = *builder.rng.pick(&builder.programs[root].children);
+    loop {
+        let children = &builder.programs[wrong].children;
+        if children.len() < 3 || builder.rng.below(3) == 0 {
+            break;
+        }
+        wrong = *builder.rng.pick(children);
+    }
+    let fixed_weight = builder.programs[wrong].weight;
+    let delta = builder.rng.below(9) + 1;
+    builder.programs[wrong].weight = if builder.rng.below(2) == 0 || fixed_weight <= delta {
+        fixed_weight + delta
+    } else {
+        fixed_weight - delta
+    };
+
+    let mut lines = builder.programs.iter().map(|p| {
+        let mut line = format!("{} ({})", p.name, p.weight);
+        if !p.children.is_empty() {
+            let names = p.children.iter().map(|&c| &builder.programs[c].name[..]).collect::<Vec<_>>();
+            line.push_str(" -> ");
+            line.push_str(&names.join(", "));
+        }
+        line + "\n"
+    }).collect::<Vec<_>>();
+    builder.rng.shuffle(&mut lines);
+    Tower { text: lines.concat(), root: builder.programs[root].name.clone(), fixed_weight }
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    tower(rng, size).text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(7);
+        for n in 0..30 {
+            let tower = tower(&mut rng, &Size { max_depth: n % 5, max_children: 4, max_weight: 100 * n as u64 });
+            let parsed = Day::parse(&tower.text).unwrap();
+            assert_eq!(Day::part1(&parsed).unwrap(), tower.root, "{}", tower.text);
+            assert_eq!(Day::part2(&parsed).unwrap(), tower.fixed_weight, "{}", tower.text);
+        }
+    }
+}
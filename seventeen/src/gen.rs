@@ -0,0 +1,38 @@
+use common::Rng;
+
+/// How far the spinlock can step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub max_step: usize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { max_step: 400 }
+    }
+}
+
+pub fn step(rng: &mut Rng, size: &Size) -> usize {
+    rng.below(size.max_step.max(1) as u64) as usize + 1
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format!("{}\n", step(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(17);
+        for n in 1..30 {
+            let step = step(&mut rng, &Size { max_step: n });
+            assert_eq!(Day::parse(&format!("{}\n", step)).unwrap(), step);
+            Day::part1(&step).unwrap();
+        }
+    }
+}
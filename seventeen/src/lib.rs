@@ -1,5 +1,10 @@
+extern crate common;
+
+use common::{Error, ParseError, Solution};
 use std::fmt;
 
+pub mod gen;
+
 #[derive(Clone, PartialEq)]
 struct SpinLock {
     buf: Vec<u64>,
@@ -28,7 +33,7 @@ impl SpinLock {
             buf: v,
             cur: 0,
             num: 0,
-            step: step,
+            step,
         }
     }
 
@@ -49,25 +54,74 @@ impl SpinLock {
     }
 }
 
-fn run(a: usize) -> u64 {
+pub fn run(a: usize) -> u64 {
     let mut s = SpinLock::new(a);
-    for i in 0..2017 {
-        s.advance();
+    for _ in 0..2017 {
+        s.advance().expect("Could not advance");
     }
     s.next().expect("WHOOPS")
 }
 
+// 0 never moves from the front of the buffer, so we only need to track what lands right after it
+fn after_zero(step: usize, insertions: usize) -> usize {
+    let mut cur = 0;
+    let mut after = 0;
+    for num in 1..(insertions + 1) {
+        cur = ((cur + step) % num) + 1;
+        if cur == 1 {
+            after = num;
+        }
+    }
+    after
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = usize;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<usize, Error> {
+        let step = input.trim();
+        Ok(step.parse().map_err(|_| ParseError::at(input, step, "a step size"))?)
+    }
+
+    fn part1(step: &usize) -> Result<u64, Error> {
+        Ok(run(*step))
+    }
+
+    fn part2(step: &usize) -> Result<usize, Error> {
+        Ok(after_zero(*step, 50_000_000))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::Differential;
 
     #[test]
-    fn it_works() {
-        assert_eq!(run(3), 638);
+    fn after_zero_matches_spinlock() {
+        let spin = |&(step, insertions): &(usize, usize)| {
+            let mut s = SpinLock::new(step);
+            for _ in 0..insertions {
+                s.advance().expect("Could not advance");
+            }
+            s.buf.get(1).map_or(0, |&n| n as usize)
+        };
+        Differential::new(100).run(|rng| (rng.below(400) as usize, rng.below(2018) as usize),
+                                   spin,
+                                   |&(step, insertions)| after_zero(step, insertions));
     }
 
     #[test]
-    fn answer() {
-        println!("{}", run(324));
+    fn it_works() {
+        assert_eq!(run(3), 638);
+        assert_eq!(after_zero(3, 9), 9);
+        assert_eq!(after_zero(3, 4), 2);
+        assert_eq!(Day::parse("3\n").unwrap(), 3);
+        assert_eq!(Day::parse("-3\n").unwrap_err(),
+                   Error::Parse(ParseError::new(1, 1, "-3", "a step size")));
     }
 }
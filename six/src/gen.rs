@@ -0,0 +1,46 @@
+use common::Rng;
+
+/// How many memory banks to make, and how full they can get
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub banks: usize,
+    pub max_blocks: u32,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { banks: 16, max_blocks: 15 }
+    }
+}
+
+pub fn banks(rng: &mut Rng, size: &Size) -> Vec<u32> {
+    (0..size.banks.max(1)).map(|_| rng.below(u64::from(size.max_blocks) + 1) as u32).collect()
+}
+
+pub fn format(banks: &[u32]) -> String {
+    banks.iter().map(|b| b.to_string()).collect::<Vec<_>>().join("\t") + "\n"
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&banks(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(6);
+        for n in 1..10 {
+            let banks = banks(&mut rng, &Size { banks: n, max_blocks: 255 });
+            let parsed = Day::parse(&format(&banks)).unwrap();
+            assert_eq!(parsed, banks);
+            assert!(Day::part2(&parsed).unwrap() <= Day::part1(&parsed).unwrap());
+        }
+        let small = banks(&mut rng, &Size::default());
+        Day::part2(&Day::parse(&format(&small)).unwrap()).unwrap();
+    }
+}
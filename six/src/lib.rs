@@ -1,32 +1,53 @@
+extern crate common;
+
+use common::{Error, Observer, ParseError, Silent, Solution};
 use std::collections::HashMap;
 
-fn parse_input(input: &str) -> Vec<u8> {
+pub mod gen;
+
+// the total is checked as well, so that no bank can overflow while blocks are moved around
+fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut total = 0u32;
     let nums = input.split_whitespace()
-                    .filter(|s| !s.is_empty())
-                    .map(|s| s.parse().expect("Could not parse number"))
-                    .collect();
-    nums
+                    .map(|s| {
+                        let n = s.parse().map_err(|_| ParseError::at(input, s, "a number of blocks"))?;
+                        total = total.checked_add(n)
+                                     .ok_or_else(|| ParseError::at(input, s, "no more than 4294967295 blocks in all"))?;
+                        Ok(n)
+                    })
+                    .collect::<Result<Vec<u32>, _>>()?;
+    if nums.is_empty() {
+        return Err(ParseError::at(input, &input[input.len()..], "at least one memory bank"));
+    }
+    Ok(nums)
 }
 
-pub fn run(input: &str) -> usize {
-    let mut banks = parse_input(input);
-    let mut states: HashMap<Vec<u8>, usize> = HashMap::new();
+// returns how many cycles it takes to see a configuration twice, and how long the loop is
+fn detect(banks: Vec<u32>) -> (usize, usize) {
+    detect_with(banks, &mut Silent)
+}
+
+/// Like `detect`, showing `observer` every configuration the banks pass through
+pub fn detect_with<O: Observer<[u32]>>(mut banks: Vec<u32>, observer: &mut O) -> (usize, usize) {
+    let mut states: HashMap<Vec<u32>, usize> = HashMap::new();
+    let mut steps = 0;
     loop {
-        for val in states.values_mut() {
-            *val = *val + 1;
-        }
-        println!("{:?}", &banks);
+        observer.observe(&banks);
+        states.insert(banks.clone(), steps);
         cycle(&mut banks);
-        if states.contains_key(&banks) {
-            println!("states: {:?}", &states);
-            return *states.get(&banks).unwrap()
+        steps += 1;
+        if let Some(seen) = states.get(&banks) {
+            return (steps, steps - seen);
         }
-        states.insert(banks.clone(), 0);
     }
 }
 
-fn idx_of_max(banks: &[u8]) -> usize {
-    let m = banks.iter().enumerate().fold((0usize, 0u8), |acc, i| {
+pub fn run(input: &str) -> Result<usize, Error> {
+    Ok(detect(parse_input(input)?).1)
+}
+
+fn idx_of_max(banks: &[u32]) -> usize {
+    let m = banks.iter().enumerate().fold((0usize, 0u32), |acc, i| {
         let max_idx = acc.0;
         let max_num = acc.1;
         let idx = i.0;
@@ -40,9 +61,9 @@ fn idx_of_max(banks: &[u8]) -> usize {
     m.0
 }
 
-fn cycle(banks: &mut Vec<u8>) {
+fn cycle(banks: &mut [u32]) {
     let mut it = (0..(banks.len())).cycle().peekable();
-    let idx = idx_of_max(&banks);
+    let idx = idx_of_max(banks);
     // advance the cyclical iterator to the index of the bank with the most blocks
     loop {
         if let Some(i) = it.peek() {
@@ -61,21 +82,60 @@ fn cycle(banks: &mut Vec<u8>) {
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<u32>, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(banks: &Vec<u32>) -> Result<usize, Error> {
+        Ok(detect(banks.clone()).0)
+    }
+
+    fn part2(banks: &Vec<u32>) -> Result<usize, Error> {
+        Ok(detect(banks.clone()).1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_input() {
-        let input = "14 0   15  12  11  11  3   5   1   6   8   4   9   1   8   4";
-        let num = run(&input);
-        println!("answer is: {}", &num);
+    fn given_test() {
+        let input = "0\t2\t7\t0";
+        assert_eq!(run(input).unwrap(), 4);
+        assert!(run("0 2 x").is_err());
+        assert_eq!(Day::part1(&Day::parse(input).unwrap()).unwrap(), 5);
     }
 
     #[test]
-    fn given_test() {
-        let input = "0\t2\t7\t0";
-        let num = run(&input);
-        assert_eq!(num, 4);
+    fn observed_states() {
+        let mut states = vec![];
+        detect_with(vec![0, 2, 7, 0], &mut |banks: &[u32]| states.push(banks.to_vec()));
+        assert_eq!(states, vec![vec![0, 2, 7, 0], vec![2, 4, 1, 2], vec![3, 1, 2, 3],
+                                vec![0, 2, 3, 4], vec![1, 3, 4, 1]]);
+    }
+
+    #[test]
+    fn bad_banks() {
+        assert_eq!(Day::parse("0 2 -7 0").unwrap_err(),
+                   Error::Parse(ParseError::new(1, 5, "-7", "a number of blocks")));
+        assert_eq!(Day::parse("0 4294967295 1").unwrap_err(),
+                   Error::Parse(ParseError::new(1, 14, "1", "no more than 4294967295 blocks in all")));
+        assert_eq!(Day::parse(" \n").unwrap_err(),
+                   Error::Parse(ParseError::new(2, 1, "", "at least one memory bank")));
+    }
+
+    #[test]
+    fn full_banks() {
+        let banks = Day::parse("255 255").unwrap();
+        assert_eq!(Day::part1(&banks).unwrap(), 10);
+        assert_eq!(Day::part2(&banks).unwrap(), 2);
     }
 }
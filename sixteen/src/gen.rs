@@ -0,0 +1,54 @@
+use common::Rng;
+use {Instruction, DANCERS};
+
+/// How long a dance to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub moves: usize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { moves: 10_000 }
+    }
+}
+
+pub fn moves(rng: &mut Rng, size: &Size) -> Vec<Instruction> {
+    let dancers = DANCERS.chars().collect::<Vec<_>>();
+    let len = dancers.len() as u64;
+    (0..size.moves).map(|_| match rng.below(3) {
+        0 => Instruction::Spin(rng.below(len) as usize + 1),
+        1 => Instruction::Exchange(rng.below(len) as usize, rng.below(len) as usize),
+        _ => Instruction::Partner(*rng.pick(&dancers), *rng.pick(&dancers)),
+    }).collect()
+}
+
+pub fn format(moves: &[Instruction]) -> String {
+    moves.iter().map(|m| match *m {
+        Instruction::Spin(s) => format!("s{}", s),
+        Instruction::Exchange(a, b) => format!("x{}/{}", a, b),
+        Instruction::Partner(a, b) => format!("p{}/{}", a, b),
+    }).collect::<Vec<_>>().join(",") + "\n"
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&moves(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(16);
+        for n in 0..30 {
+            let moves = moves(&mut rng, &Size { moves: n });
+            let parsed = Day::parse(&format(&moves)).unwrap();
+            assert_eq!(parsed, moves);
+            Day::part2(&parsed).unwrap();
+        }
+    }
+}
@@ -1,102 +1,152 @@
-#![feature(slice_rotate)]
+extern crate common;
 
+use common::{Error, ParseError, Solution};
 use std::collections::HashMap;
 
-#[derive(Hash, Debug, Clone, PartialEq, Eq)]
-pub enum Instruction<'a> {
+pub mod gen;
+
+/// The programs lined up at the start of the dance
+pub const DANCERS: &str = "abcdefghijklmnop";
+
+#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
     Spin(usize),
     Exchange(usize, usize),
-    Partner(&'a str, &'a str),
+    Partner(char, char),
+}
+
+fn parse_position(input: &str, s: &str) -> Result<usize, ParseError> {
+    match s.parse() {
+        Ok(n) if n < DANCERS.len() => Ok(n),
+        _ => Err(ParseError::at(input, s, format!("a position below {}", DANCERS.len()))),
+    }
+}
+
+fn parse_dancer(input: &str, s: &str) -> Result<char, ParseError> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if DANCERS.contains(c) => Ok(c),
+        _ => Err(ParseError::at(input, s, format!("a program from a to {}", DANCERS.chars().last().unwrap()))),
+    }
 }
 
-fn parse_spin(instr: &str) -> Instruction {
+// the two halves of `x3/4` or `pe/b`
+fn parse_pair<'a>(input: &str, instr: &'a str) -> Result<(&'a str, &'a str), ParseError> {
     let part = &instr[1..];
-    let num = part.parse().expect(&format!("Could not parse {} as a number", part));
-    Instruction::Spin(num)
+    let mut parts = part.splitn(2, '/');
+    let left = parts.next().unwrap();
+    let right = parts.next().ok_or_else(|| ParseError::at(input, &part[part.len()..], "`/`"))?;
+    Ok((left, right))
 }
 
-fn parse_exchange(instr: &str) -> Instruction {
+fn parse_spin(input: &str, instr: &str) -> Result<Instruction, ParseError> {
     let part = &instr[1..];
-    let mut parts = part.split("/");
-    let left = parts.next().expect("Error parsing Exchange instruction");
-    let right = parts.next().expect("Error parsing Exchange instruction");
-    let left = left.parse().expect("Error parsing Exchange instruction");
-    let right = right.parse().expect("Error parsing Exchange instruction");
-    Instruction::Exchange(left, right)
+    match part.parse() {
+        Ok(n) if n <= DANCERS.len() => Ok(Instruction::Spin(n)),
+        _ => Err(ParseError::at(input, part, format!("a spin of up to {}", DANCERS.len()))),
+    }
 }
-fn parse_partner(instr: &str) -> Instruction {
-    let left = &instr[1..2];
-    let right = &instr[3..4];
-    Instruction::Partner(left, right)
+
+fn parse_exchange(input: &str, instr: &str) -> Result<Instruction, ParseError> {
+    let (left, right) = parse_pair(input, instr)?;
+    Ok(Instruction::Exchange(parse_position(input, left)?, parse_position(input, right)?))
 }
 
-fn parse_instruction(instr: &str) -> Instruction {
+fn parse_partner(input: &str, instr: &str) -> Result<Instruction, ParseError> {
+    let (left, right) = parse_pair(input, instr)?;
+    Ok(Instruction::Partner(parse_dancer(input, left)?, parse_dancer(input, right)?))
+}
+
+fn parse_instruction(input: &str, instr: &str) -> Result<Instruction, ParseError> {
     match instr {
-        s if s.starts_with("s") => parse_spin(s),
-        e if e.starts_with("x") => parse_exchange(e),
-        p if p.starts_with("p") => parse_partner(p),
-        u => panic!(format!("unknown instruction {}", u)),
+        s if s.starts_with('s') => parse_spin(input, s),
+        e if e.starts_with('x') => parse_exchange(input, e),
+        p if p.starts_with('p') => parse_partner(input, p),
+        u => Err(ParseError::at(input, u, "a spin, exchange or partner move")),
     }
 }
 
-fn main() {
-    let input = r#"
-        pn/m,x10/11,s12,x15/8,pl/o,x4/9,pn/h,x10/1,s15,x8/2,s15,x4/15,s12,x3/1,pa/k,s13,x0/2,s11,pb/m,x11/6,s5,x3/15,s10,x7/10,pn/l,x8/2,s13,x7/13,s15,x3/9,pj/p,x14/13,s14,x10/15,s4,x4/9,s6,x11/14,pc/i,x10/3,s15,x1/12,s15,x9/10,pg/p,x2/14,s2,ph/e,x1/5,s6,x6/14,pb/d,x2/13,pp/l,s5,x4/6,pk/o,x13/3,s6,x7/10,s6,x11/1,pm/h,s1,x0/15,s5,x11/8,s11,x9/4,pd/n,x8/11,pb/i,s8,pa/f,s13,x4/6,pe/o,x10/1,s8,x12/5,s13,x3/6,s11,pd/b,x5/9,pn/m,x0/6,pa/l,s11,pj/f,x3/12,s3,x15/13,s4,x3/9,ph/o,x14/0,pe/b,x3/9,pd/k,x6/14,pe/i,s9,x11/0,pp/c,x1/12,pg/d,x13/4,s14,x2/6,s3,x7/15,s12,x13/14,pm/h,s8,x2/8,pb/j,s10,x9/4,ph/p,s6,x7/15,s3,x14/9,pc/g,x11/0,s11,x5/7,s5,x9/6,s13,x4/15,pf/h,s14,x13/14,s10,x6/2,s12,x13/0,s13,x1/9,s2,pc/m,x14/6,s4,x12/5,pb/a,x11/13,pe/j,x6/4,s11,x15/13,pk/o,x5/0,s11,x9/11,s9,pj/i,x14/7,s6,x3/12,s7,x5/14,s1,x10/12,pl/p,x5/13,pb/c,x8/10,pd/g,x15/0,s10,x12/13,pf/e,x0/1,s8,x3/11,s10,x2/12,s7,x5/13,pl/m,s13,x4/1,s11,x0/15,pn/h,x9/4,s4,x1/14,pa/b,x10/8,s9,x13/5,s5,x4/2,pi/f,s2,x6/10,pp/k,x7/0,pg/n,x13/15,pd/i,x12/2,pj/n,x4/7,s5,x3/12,s15,x14/5,s14,x13/1,s7,x7/0,pp/o,x2/5,s14,x9/13,s7,x5/11,s2,x12/6,s12,x13/5,s5,x0/7,s11,x15/10,s14,x13/3,s7,pm/d,x9/5,s15,x3/11,s9,x12/7,pf/c,x4/15,s2,x12/6,s9,x4/13,s7,x14/1,pb/g,x9/3,pk/m,x6/0,s14,x10/9,pd/b,x0/6,pf/p,x15/1,pe/o,x9/5,pg/p,s11,x7/6,s11,x10/1,s14,x14/6,s12,x1/15,s2,x6/5,pj/a,x4/0,pb/h,x12/7,pk/e,s13,x9/10,s1,x8/15,pj/c,x10/1,s7,x6/14,s8,x13/9,s1,x7/8,pi/n,x0/11,pb/a,x2/8,s5,x7/10,s11,pp/g,s9,x12/5,s2,x11/1,pj/a,s7,x8/2,s13,x0/14,s5,x12/6,s6,x14/13,s8,x12/9,pk/p,x8/6,s3,x3/5,pc/f,x6/13,pb/m,x12/9,s13,x8/7,s11,x4/6,s7,x15/1,s8,pp/e,s11,x6/14,s1,x15/2,s3,x5/1,pd/h,x10/12,s4,pk/n,x6/14,s8,x8/12,po/m,x11/14,s2,x6/0,s6,x4/8,s11,x1/9,pj/g,x2/4,s10,pk/f,x15/0,s8,x13/1,ph/e,x8/10,s7,x6/2,pc/d,x1/7,s15,x12/6,s9,pg/p,x8/14,s11,x2/15,s11,x13/11,s1,x15/4,s5,x13/3,pb/h,x5/6,pm/f,x14/2,s13,x11/1,s5,x9/10,pi/n,x3/6,s3,x1/4,pm/k,s6,x10/11,s14,x15/1,ph/c,x13/5,pp/i,x4/3,s3,x6/11,s5,x3/8,s2,x0/7,s5,x3/6,s6,x14/5,pc/d,x12/13,s8,x5/9,s8,x12/13,s9,x8/3,s14,x15/13,pf/j,x4/3,pk/a,x10/1,s15,x13/14,s14,pd/c,x10/15,po/h,s13,x5/1,s8,x12/10,pm/g,s3,x11/1,pp/h,x10/6,s9,x2/5,s1,x13/6,s9,x9/3,pa/m,s3,x6/13,s8,x7/11,pd/p,x0/8,pf/j,s11,ph/b,x1/2,s2,x12/10,pm/c,x6/9,s3,x3/15,s15,x11/6,po/j,x9/7,pp/l,x8/6,pg/i,x2/4,s11,x12/14,ph/c,s9,pg/e,x4/15,pm/f,x5/13,pp/j,x6/3,po/n,x1/11,pl/b,x15/3,s10,x14/13,pe/n,x15/10,s7,x6/0,s11,x1/11,s3,x6/3,s11,x4/12,pg/d,x8/1,s6,x5/9,s10,x12/7,s4,x10/9,s6,x4/2,s14,x12/8,s3,x10/11,pa/b,x4/8,pi/l,s12,pg/f,s13,x3/2,pe/p,x0/14,s5,x12/1,s8,x9/3,pg/h,s8,x8/13,pc/b,s11,pp/d,x12/10,pc/f,s3,x1/11,s9,x12/4,s14,x2/14,s8,pg/p,x1/9,s10,x11/7,s7,x0/12,s4,x2/1,s5,x0/11,s7,x3/10,s7,x0/15,s6,x5/11,s14,x4/8,s13,x6/12,pa/i,x8/4,pd/j,x2/0,s3,x12/13,s14,x4/15,s12,x7/13,s8,x10/14,pa/k,s9,x1/0,s9,x2/13,s13,x6/3,pe/g,x13/10,pk/j,x0/15,s1,x2/10,s11,x4/12,s2,x3/8,s12,x9/4,pe/c,x15/7,s8,x0/1,s8,x15/6,pm/a,x3/5,pe/h,x7/1,s2,x10/5,s11,x7/6,s3,x4/14,pk/d,s2,x9/15,pa/f,x1/7,s10,x5/15,pd/b,x9/14,s8,x7/1,pa/k,x8/11,pj/l,x5/10,pd/g,x8/7,s1,x9/0,pi/e,x15/2,s9,x7/1,s14,x3/15,s15,pj/m,x6/12,ph/i,x13/7,pj/c,x14/0,s14,x11/1,pk/l,x0/9,pg/d,x1/2,pb/n,x9/15,pi/c,x4/6,pe/m,x13/12,s1,x2/15,s13,x13/3,s12,x7/11,s14,x10/13,pl/j,x3/7,pf/d,x14/0,s9,pa/e,x8/5,pg/n,x14/12,s7,x3/7,s3,x8/12,pe/l,x0/13,po/h,x15/5,s12,x12/14,s4,x0/11,s14,x2/13,pj/a,x14/15,s12,pg/n,x11/5,pj/d,x7/4,s1,x2/13,pg/n,s13,x3/12,s5,x0/4,pm/e,x5/15,s3,x1/3,s2,pl/k,x13/10,s15,x5/14,pm/c,x9/15,s9,x0/13,s9,x12/14,s14,pl/k,x11/4,ph/f,x7/6,pl/g,x0/12,pi/e,x3/7,pa/p,s12,x2/12,s5,x1/5,po/g,s11,x0/3,pj/p,x4/12,s11,x14/0,ph/g,x11/5,s11,x8/9,pj/f,s9,x4/2,po/i,x11/13,s2,x2/6,pm/l,x15/12,po/k,s7,x5/4,s6,x0/1,pb/p,x7/9,s2,x1/6,pl/k,x0/13,s8,x1/12,s9,x10/15,pb/o,x1/2,s5,x13/14,s15,x11/2,s11,x0/6,s3,x3/11,s3,x1/9,s10,x3/8,ph/i,s10,x13/15,s6,x7/6,s5,x10/8,s14,x0/2,po/g,x11/3,pk/i,x4/12,s15,x11/6,pf/e,x4/5,s15,x15/9,s15,x5/7,s9,x1/4,s7,x9/12,s10,x8/4,pm/a,x11/2,s9,pp/c,x3/0,s7,x10/12,pn/g,x1/3,s9,x10/11,s11,x1/13,s4,pb/k,x15/5,po/n,x9/7,s13,x6/0,pi/e,x1/12,s10,x14/0,s2,x15/7,s6,x11/4,pm/h,x14/8,pd/o,x4/15,pa/j,x2/7,s2,x13/4,s3,x10/9,pb/k,x5/1,s8,x13/11,s12,x4/14,pe/n,x11/12,pk/m,x13/5,s1,x9/12,s13,x8/13,pg/e,x10/3,s12,x11/14,pm/d,x7/10,s10,x8/4,pp/h,x7/6,s13,x0/8,s4,x13/14,s2,x4/11,pa/m,x6/0,pn/h,x15/8,s2,x9/3,po/g,x8/7,s7,x9/1,s12,x14/13,s10,x9/5,s5,x2/11,s8,x7/1,s4,x3/12,pk/l,x0/15,pc/h,x12/11,pg/l,s12,x10/5,s1,x14/12,s6,x0/15,s11,x10/5,s10,x7/6,s7,x2/1,s7,x12/9,pn/k,x14/8,s8,x7/3,pa/g,x6/12,s8,pk/j,x11/0,s5,x8/15,s4,x1/2,s5,x3/7,pf/l,x4/12,s10,x9/5,s12,x8/12,s13,x4/5,s2,pk/j,x3/12,pp/c,s15,x2/6,s13,x13/9,s5,x2/10,pk/d,x13/6,s7,x8/4,po/c,s3,x0/2,s14,x9/12,pk/f,x7/4,s5,x13/1,s10,x12/11,pc/o,x6/15,pj/g,x2/4,pe/a,s8,x15/8,pj/c,x5/11,pb/f,x12/3,pd/p,x1/14,s7,x9/8,s11,x13/12,pa/m,x11/1,s11,x13/6,po/i,x3/5,s13,x4/2,s12,x15/3,pe/d,x13/0,s1,x1/11,pa/f,x5/0,s15,x2/9,s1,x5/14,s9,x1/7,s14,x0/14,s4,x10/1,s13,x12/15,pm/e,x1/6,s5,x8/10,s13,pj/k,x2/0,pc/n,s14,x7/15,s2,x5/11,pa/m,x9/7,pj/o,x5/12,s13,x13/10,pd/l,s9,x5/3,s3,x10/8,pi/m,x2/13,s7,x8/14,s14,x4/5,s4,x12/13,s12,x15/14,s9,x8/11,pc/p,x0/4,ph/n,x9/12,pg/k,x5/10,pc/m,x3/1,s11,x9/12,pl/k,x0/8,s11,x13/6,pi/f,x1/15,pg/c,x0/12,pp/d,x13/4,pk/c,x15/2,s13,x12/8,s8,x6/7,s7,x5/11,s15,x9/8,s1,x15/3,s12,x2/7,s9,x15/1,s5,x12/7,s6,x11/0,s6,x3/1,pa/i,x0/6,s8,x7/11,s9,x10/13,s11,x2/0,pp/g,x8/13,s12,x7/11,s13,x6/15,s1,x8/7,pl/j,x3/2,s4,po/k,x9/13,pa/n,x3/2,pm/e,x8/5,pg/k,x12/14,s13,x3/4,pl/p,s10,x15/13,s3,x4/10,pe/n,s4,x0/3,s13,x15/8,s10,x9/3,s4,x8/5,s5,x1/14,s12,pf/o,x12/2,s14,x6/11,s10,x1/9,s2,pd/l,x5/10,s11,x9/0,s11,x5/11,s4,x2/7,s6,x0/13,s7,x12/3,s3,x9/13,s15,pa/h,x12/0,s6,x6/1,s11,x8/14,s11,x9/7,s6,x5/13,s1,x7/11,po/c,x10/0,pd/n,x9/14,s13,x12/2,pc/i,x13/6,s12,x12/5,pk/m,x6/14,s5,x3/0,s4,x11/9,pf/h,x2/4,pk/c,x0/13,pj/m,x9/3,po/c,x1/10,pl/a,s2,x14/2,pj/b,x13/1,pk/n,x9/15,pc/p,x8/5,s2,x10/15,s1,x2/14,s14,x9/13,s6,x0/1,s14,x9/2,s15,x14/8,s1,x4/9,s7,x6/13,pa/h,x5/8,s12,x4/10,s9,x11/3,s1,x9/8,pm/j,x15/7,s11,x12/5,s2,x2/0,s13,pd/o,s3,x14/3,pp/n,x4/0,pe/g,s14,pk/m,x9/11,pj/l,s3,x4/2,pd/e,x6/0,s6,x15/1,pc/g,x14/3,s15,x10/7,s10,x12/14,s10,x13/5,s10,x15/9,s11,x11/7,pi/b,x10/15,s2,x13/14,s5,x7/6,s15,x11/9,s7,x14/8,pg/a,x4/0,s7,x15/11,s7,x2/3,pf/c,x1/5,pm/l,x10/11,s6,x0/8,pn/k,s3,x2/13,s11,x12/15,s7,x11/6,s10,x0/2,ph/p,x3/1,s2,x15/10,s15,x5/6,s12,x11/4,s9,x5/13,pj/c,s12,x11/6,s1,x14/3,s4,x6/15,s2,x5/0,s8,x13/10,s13,x12/11,s5,x7/8,s7,x15/14,pe/l,x8/12,pf/i,x6/1,s6,x7/13,pj/a,x6/3,s12,x1/10,s14,x12/0,pn/p,x9/15,s8,x11/10,pm/l,x7/12,s11,x6/15,po/k,x10/2,s2,pa/e,x8/14,s15,ph/o,s3,x12/13,s6,x1/2,s9,pl/j,s5,pk/c,x9/0,pn/a,x12/7,s2,x8/0,pc/f,x14/2,s9,x12/6,pp/j,x7/15,s6,x0/13,s2,x2/15,s8,x5/1,pe/l,s1,x7/4,pg/d,x6/8,ph/c,s6,x15/11,s5,x14/6,pi/g,s7,x10/11,s13,x14/1,s13,x4/12,s6,x15/6,s15,pb/k,s13,x1/14,s13,x5/15,pl/p,s10,x6/11,s12,x5/2,pn/o,x4/14,s2,x11/9,pi/j,x5/1,s11,x11/6,s4,pe/m,x10/8,pl/d,s5,x15/6,pe/h,x3/0,s14,x11/13,pj/d,s1,pi/n,x12/9,s4,x10/4,pf/h,x14/3,pb/m,x8/13,s6,x1/0,s6,x4/7,s13,x9/6,s1,x12/2,s9,x5/7,s2,x15/14,s10,x1/12,s12,pf/k,x6/13,s3,x9/14,pd/j,x4/3,s7,x12/6,s11,x10/1,s13,x14/2,s10,x6/4,pg/e,x14/3,s4,x2/4,s12,x11/9,pj/o,s11,x10/1,pa/g,x13/0,pj/n,x7/4,s6,x9/1,s8,x11/4,pe/h,x1/9,s10,x4/8,pn/l,x11/1,s3,x0/3,s14,x4/14,pi/p,x0/1,s13,x15/10,s12,x5/8,s15,x13/7,pn/g,x4/14,s12,x6/9,s4,x3/13,s10,x7/9,s1,x11/3,s1,x14/12,pm/c,s4,x2/1,s2,pk/a,x6/0,s5,x8/13,pi/b,x12/7,pe/c,x10/13,s4,pk/a,s15,x7/2,s1,x5/0,s14,x3/8,s1,x13/11,s10,ph/b,s10,x6/14,s12,x8/10,s3,pm/j,x1/12,po/i,x8/6,s7,x11/13,s14,x5/4,pg/n,x7/10,s13,x5/11,pi/j,x1/2,pp/k,x10/3,pd/g,s15,x0/5,pa/o,x4/6,pi/e,x8/1,s14,x4/6,s10,x13/2,s9,x12/3,s8,x10/2,pn/b,x14/8,s1,x13/9,pf/k,x7/3,pc/n,s1,x13/10,pk/h,x11/0,pa/e,x12/3,s11,x1/4,s6,po/m,x5/6,pl/p,s2,x3/14,s10,x11/0,s8,x4/13,s2,x6/14,s1,x3/7,s10,x10/9,s2,x7/11,s9,x8/5,s15,x3/14,s14,x4/9,s2,x3/5,pm/o,s1,x12/11,s11,x6/5,pk/a,x10/15,s8,pj/d,x1/0,s3,x10/6,s2,x3/12,pc/f,x0/14,s14,x7/10,pd/o,x14/11,pn/b,x7/10,s8,x2/15,pe/a,x12/7,pi/d,s2,x0/6,pg/m,x11/13,pe/c,x3/9,ph/k,x2/1,pn/a,x9/8,s12,x2/10,s4,x13/1,pl/c,x2/9,pn/k,x12/13,s4,x11/15,s8,pm/i,x10/6,pa/d,x1/11,s3,x7/15,s4,x4/1,s3,x13/5,pj/k,x4/14,pl/m,x13/1,s8,x8/6,s4,x1/5,s15,x0/12,s7,x3/13,s7,x2/11,pc/i,x6/4,pk/j,x5/0,pb/i,x2/15,pj/e,x4/14,s11,x2/6,s15,x8/4,s3,x13/2,pf/d,x8/1,po/k,s9,x11/13,pl/j,s15,x12/1,pc/m,x11/14,s15,x15/5,ph/d,x2/3,s8,x11/6,pk/i,x3/4,s10,x15/2,pa/l,x5/10,po/b,s14,x3/12,pj/c,x11/9,pi/l,x10/15,s1,x9/13,s8,x7/15,pd/c,x4/6,s6,x11/7,s2,x5/3,pb/j,x13/10,s9,x0/11,pc/d,x7/13,pk/e,x4/1,s10,x10/15,s3,x9/0,pn/g,x10/4,s1,x13/5,pe/j,x0/12,s2,x6/5,pm/f,x14/0,s7,x10/8,pg/d,x12/5,s2,x1/8,pc/n,x6/11,s11,x3/15,s5,x14/1,po/e,x11/0,s14,x4/13,s13,pm/j,x8/5,pd/n,x4/9,s9,x12/6,s15,x1/5,s2,x4/14,s13,x6/1,s12,x9/15,s14,x4/12,pk/o,s6,x1/2,pm/i,s1,pf/b,s12,x14/10,s15,pa/c,s12,x12/11,s15,x5/0,pg/b,x12/13,s5,x5/8,s11,x2/0,s4,x11/4,s9,x0/12,s13,x15/8,pk/i,x4/7,pm/a,x0/10,s6,x11/14,s14,x9/5,pi/n,x11/1,s14,x13/8,pk/g,x5/6,s7,x14/9,s6,x7/2,s6,x11/0,pl/b,x4/15,pp/e,x0/13,s5,x12/5,s13,x4/13,pc/a,x15/7,s10,x11/9,pe/h,x0/8,s15,x9/10,pi/b,x3/4,s1,x1/2,pg/e,x10/13,s14,x6/12,s2,x8/9,pd/m,x14/15,pi/l,x7/9,pn/f,x11/6,pm/c,x10/9,s15,x5/15,pb/e,x13/12,pd/c,x4/1,s7,x3/13,s12,x4/0,s3,x8/1,pk/p,x12/6,pe/n,x1/11,pl/c,x15/12,po/d,s2,x11/14,s10,x12/13,pm/e,x0/2,s1,x11/15,s7,x7/8,s10,x3/4,s10,pp/f,x2/10,pd/o,x0/4,s3,x5/7,pa/g,x2/4,pi/p,x8/0,s14,x3/12,s11,x10/13,s11,x7/3,pd/g,x5/13,pp/c,x8/15,s2,x6/3,s15,x1/5,pd/a,x11/10,pn/o,x8/6,s9,x2/12,s10,x10/6,pd/m,x9/4,pe/l,x1/3,pn/o,x15/2,s14,x8/13,s12,x3/11,s1,x2/4,s6,pm/d,s15,x11/6,s10,x7/1,s10,x0/6,po/k,x2/15,s3,x13/12,s10,x0/6,pj/e,x12/9,s4,x10/4,s12,x5/14,s1,x9/1,pk/h,x0/3,pb/c,x15/5,po/g,x9/8,pf/c,x3/11,pg/d,x6/10,s5,x1/9,pa/k,s1,x14/13,s15,x2/8,s6,x0/12,pc/f,x11/5,s1,x14/4,s14,x10/0,s3,x7/14,s11,x9/3,pi/m,s13,x10/14,pk/e,x13/11,s12,x3/10,s4,x4/12,pn/m,x11/13,pp/j,x4/9,s7,x8/10,pe/b,s5,x9/0,s14,x8/6,s15,x2/15,pa/c,x8/9,pg/i,x14/3,pp/k,x9/7,s2,x12/10,s14,x7/11,s7,x10/0,s10,x1/3,pb/f,x5/12,s15,x2/14,pp/l,x5/11,s3,x1/4,s2,x3/7,s14,pi/m,x14/4,s6,po/l,x15/7,s7,x5/8,s9,x15/10,s2,x0/6,ph/j,x9/1,s11,x8/13,s2,x9/10,s1,x14/5,s11,pp/c,x0/6,pn/h,x2/13,s1,x3/8,pj/o,x6/5,s11,x4/15,s7,x7/6,s11,x4/12,pa/p,x5/10,po/d,x7/3,pn/k,x6/11,s10,x15/7,pf/a,x5/3,s5,x6/8,pi/n,x12/7,po/p,x1/2,s12,x10/8,s12,x5/11,pk/g,x14/4,pf/h,x10/15,po/a,x8/7,s12,x15/14,pp/b,x12/7,pd/e,x5/14,s15,x6/8,s3,x4/1,pf/o,s1,pc/k,x14/5,pd/i,s11,x6/2,pc/b,x8/13,s8,x2/5,s4,x3/4,s11,x6/11,s3,pf/i,x1/0,pc/h,x7/4,pd/f,x6/13,pp/e,x10/2,pd/a,x9/7,s10,x15/5,s3,x14/2,pf/p,x0/9,s5,x12/11,s14,x15/7,s8,x5/1,pc/m,s7,x4/2,po/i,s11,x10/0,s6,x4/3,s12,ph/a,s12,x10/0,s6,x4/6,s13,x9/13,s4,pg/e,x3/4,s6,x7/2,s8,x9/5,s3,pb/a,s7,po/e,x3/13,s5,x0/5,pf/g,x9/2,s10,x0/12,ph/p,x7/3,s5,x2/8,pi/c,x10/11,s10,x6/3,po/a,x11/0,pc/d,x9/3,s4,x6/2,s10,x12/10,s4,x11/1,s15,pb/k,x0/6,s15,pp/e,s14,x13/3,s10,x7/12,s11,x14/5,pn/g,s4,x1/13,pa/e,x10/15,pp/c,x6/4,s11,x1/15,s15,x0/4,s5,x5/3,ph/m,x10/2,pn/g,x13/15,s15,x6/4,pa/i,x3/1,pd/h,x15/5,pf/l,x2/7,ph/e,x13/0,pj/k,x11/1,s1,x3/10,s9,pf/g,x15/12,s5,x7/13,pl/a,x11/3,pe/c,x0/4,s12,x10/5,s13,x6/1,ph/k,x9/7,pn/a,x2/8,s2,x15/1,s14,x2/4,s5,x3/15,s5,x11/2,s5,x10/6,s6,x13/7,s7,x0/14,s11,x4/2,s8,x6/11,s3,x3/7,pm/l,x11/15,pc/j,x4/5,pp/b,x9/10,pn/h,s2,x11/14,s7,x2/7,pl/m,x13/6,s2,x11/3,pn/g,x4/10,s15,pk/d,x11/13,pe/n,x0/12,po/m,s1,x4/13,s10,x14/3,s4,x8/1,pg/k,s6,x12/5,pd/a,x3/13,pn/g,s15,x11/15,s8,x5/14,s7,x12/2,s14,x4/8,pm/c,x9/10,s7,x11/2,s3,x12/14,ph/d,x5/7,pk/o,x9/2,pj/c,x7/8,pb/h,x3/5,s7,x14/15,pn/l,s10,x7/0,s5,x12/3,pj/g,x14/2,s1,x5/9,s1,pb/k,s12,x12/6,s13,ph/g,x3/4,s2,x15/5,pn/a,x12/9,s4,pc/d,x10/11,pn/m,x1/5,pa/b,s8,x10/8,pc/p,x1/4,pd/m,s8,x0/5,pc/k,x1/10,ph/g,x4/8,s3,x6/1,s3,x2/3,pc/f,x4/10,pb/l,x1/7,s1,x13/6,s7,x14/4,s15,x13/5,s4,x1/6,pp/h,x8/5,pa/e,s5,x7/1,pp/j,x6/14,s15,x0/15,pl/h,x6/12,s4,x13/15,s3,x5/14,pm/n,x6/12,s1,x14/0,s2,x13/10,pj/o,x9/7,s12,x3/4,s14,x11/6,s13,x3/0,s3,x13/4,s1,x11/15,s5,x3/10,pi/a,x12/4,pd/n,s15,x8/0,s6,x6/11,s4,x4/8,pp/a,x13/0,s15,x14/9,pm/e,x3/11,s15,pi/c,s8,x6/13,pm/e,s9,x11/3,s7,x4/2,s5,x14/10,pj/h,s12,pa/k,x15/9,s3,x3/13,s11,x4/11,pj/i,x6/7,s2,x11/8,pm/e,x5/9,ph/n,x15/12,pi/p,x1/10,s10,x0/15,pk/d,x14/5,s8,x7/6,pa/p,x3/4,pl/i,x6/5,s13,x11/15,s7,x2/9,s12,x8/11,s11,x9/4,s14,x11/10,s4,x12/14,s10,x0/5,pm/o,x1/3,s1,pa/i,x11/6,s11,pm/g,x13/5,pi/l,x0/15,pk/h,x8/4,pj/l,x6/10,pp/f,x3/8,s4,x0/6,pa/m,x7/15,s1,x5/13,po/b,x12/6,ph/c,s7,x4/13,pf/j,x10/12,s9,x7/15,pa/d,x1/9,s14,x15/12,s9,x1/9,s1,x0/4,pn/h,x3/1,s15,x5/4,pm/o,s10,x7/0,s3,x6/8,pk/h,x7/13,po/b,x12/3,s14,x2/11,pi/a,s7,x3/8,pc/o,x9/12,s7,x11/1,pi/n,x3/15,s4,x1/2,s2,x7/5,s6,x12/10,pa/h,x7/11,s10,x8/1,pm/j,x12/2,pi/p,x0/14,s13,pl/j,x12/1,pc/m,x11/13,s13,x1/10,s12,x6/15,s10,x4/5,s14,x7/9,s12,x5/12,s5,x1/10,pb/f,x15/7,pk/g,x9/13,pe/c,s12,x5/4,s7,x15/6,s10,x0/13,pd/n,x7/12,s12,x15/2,s15,x9/10,s15,x0/11,s15,x7/1,pg/p,x13/0,pl/f,s10,x12/11,s7,pp/m,x5/1,pn/f,x3/14,pk/a,x12/13,s7,pi/g,s11,x4/6,s15,x3/0,pk/c,x12/6,s3,x5/0,pg/o,x9/4,s15,x13/2,pk/l,x12/0,po/p,x11/13,s10,pl/m,x10/4,s2,pj/d,x6/3,s9,x8/10,s12,x1/4,s7,x8/14,s2,x7/3,pa/o,x1/11,s15,x10/7,s9,x13/6,s6,x10/5,s15,x1/3,s15,x12/15,pi/b,x3/0,s11,x14/9,s10,pj/o,x10/1,pa/g,x11/13,s12,x8/15,s7,x7/1,pl/c,x3/14,pk/p,x8/6,pe/h,x7/14,pa/j,x8/12,s6,x7/5,s6,x4/13,pp/c,x3/0,pg/b,x15/2,pn/j,x10/8,s11,x6/0,s7,x8/14,po/a,x2/10,pe/g,x3/13,s6,pl/b,x5/7,pk/p,x12/4,pm/b,s4,x1/0,s13,x14/4,s5,x1/15,po/a,x11/0,s9,x10/3,s4,x7/4,pe/g,x9/6,ph/l,s3,x15/0,pa/c,x4/12,pi/m,x14/15,s13,pg/p,x0/3,s12,x13/9,s8,x6/15,s1,x3/1,s3,x10/5,pd/f,x12/4,s14,pm/a,s8,pg/c,x13/14,s4,x4/6,s7,x8/15,s5,x10/1,s6,x15/9,pd/b,x14/3,s3,x7/2,pm/f,x12/1,s14,pc/i,x5/8,s10,x12/7,s1,x11/9,s11,x10/2,s15,x12/14,s4,x1/0,s5,x10/13,pn/j,x15/4,s4,x13/8,pa/d,x15/4,s13,x2/14,pb/l,x5/12,po/n,x6/15,s7,x10/9,pa/p,x6/7,pg/j,x1/4,s14,x6/10,s12,x3/2,s13,x7/8,s9,x5/1,pm/n,x4/0,s6,x12/7,pp/i,x4/5,pk/f,x0/14,s7,x3/7,s7,x8/2,pp/e,x15/1,s11,x2/3,s4,pk/l,x11/13,s1,x0/2,s13,pd/g,x10/1,s10,x5/3,s13,x8/10,pe/o,x12/7,s2,x0/5,pn/g,x1/10,s14,x7/3,pp/d,x15/4,s12,pm/a,x5/10,pc/n,x0/7,s12,x6/3,s15,x1/2,s12,x12/0,s4,x11/4,s11,x9/15,pp/b,x8/10,pg/e,x5/4,s4,pa/h,x0/7,s1,x5/15,s4,x8/7,pf/i,x14/4,pa/e,x10/1,ph/i,x3/15,s13,x1/9,pn/k,x7/12,pi/j,x9/6,pk/p,x15/4,s13,x12/13,pn/f,x1/3,pd/e,x11/0,pn/c,x3/13,ph/o,x14/12,pa/j,x3/7,s13,x6/1,s10,x14/13,s14,x1/7,s8,x11/5,s3,x0/12,po/e,x4/7,s15,x0/14,s12,x13/9,s11,x10/15,pj/i,x8/1,pl/h,x6/3,s6,x1/15,s13,x7/12,pn/d,x1/10,s14,x11/4,s12,x0/2,s5,x13/6,pk/f,x0/5,s13,x10/4,s2,pb/l,s9,x2/1,pe/h,s7,x10/12,pf/k,x3/14,pj/c,x12/11,pl/a,x2/9,pn/k,x6/0,pe/c,s14,x2/12,s7,x10/11,s2,x9/2,s8,x15/4,pi/h,x2/11,s1,x0/6,pk/g,x10/7,s15,x0/11,s8,x8/10,s15,x3/6,pc/h,x10/0,pd/l,x12/4,pc/a,s3,x11/3,s14,x8/14,s5,x12/9,pl/d,x10/4,s5,x9/0,s14,x15/13,pp/m,x0/10,ph/a,x5/1,pi/c,x8/9,pm/d,x12/6,s1,x8/9,pl/p,x15/0,pb/m,x1/9,s14,x15/0,pg/p,x4/9,s14,x1/6,s15,x0/4,ph/k,x13/10,pf/m,x4/11,pe/b,x2/15,pj/f,x6/14,s8,x11/0,s13,x9/13,pa/d,x0/8,s1,x2/7,s2,x8/15,s6,x5/4,s4,x7/8,pg/m,x0/4,pc/i,x14/11,s3,x9/4,pp/o,x1/6,s10,x11/0,s14,x6/1,s10,x15/10,s11,x8/12,s8,x3/9,s14,x8/10,s2,x11/4,pl/h,x13/10,pk/d,x7/5,pi/m,s8,x14/9,pp/l,x12/0,pc/d,x5/4,pn/j,x7/12,pg/f,x2/13,s15,x8/15,s6,x9/12,pl/h,x0/3,s6,x13/8,s13,x14/1,s12,x5/3,s2,x11/6,s1,x13/15,s14,x0/5,s14,x1/15,s11,x4/8,pg/f,x15/0,pn/m,x4/12,s3,x7/2,pl/i,x15/8,s8,x1/13,s11,x2/6,s11,x13/0,s14,x15/8,pj/f,s6,x7/1,pm/p,x12/14,s15,x0/13,s14,x12/8,s8,x5/15,pg/h,x13/6,s5,x12/10,s9,x0/11,po/e,x7/4,s3,x1/13,s10,x7/4,s1,pk/i,x2/1,s6,x4/8,s15,x6/3,pa/b,x5/8,pe/c,x1/13,s12,x6/14,po/d,s2,x0/7,s1,x5/15,s2,x10/13,pk/g,x5/3,s1,x0/15,s3,x1/14,s14,x12/4,pp/o,x9/13,s11,x5/2,s3,x3/1,s14,x11/8,pi/e,x2/14,s14,x5/13,pg/b,x2/10,s6,x15/5,pl/o,x3/8,s3,x5/7,s3,x0/4,s14,x8/2,s7,x10/9,pa/b,x13/5,pm/i,x9/12,pn/o,x7/10,s1,x11/9,s14,x8/6,pc/h,x4/15,pe/n,x7/0,pc/j,x2/6,pp/m,x4/10,s8,x15/12,pn/k,x10/8,s9,x4/13,s1,x8/14,s9,x3/0,s6,pc/g,x11/1,pi/b,x5/9,s7,x1/4,s12,x14/0,s5,x7/12,pp/a,s6,x5/9,s5,x10/12,s1,x1/5,pm/k,x10/9,s8,x15/14,pg/n,x2/10,pa/i,x13/7,pm/j,s9,x0/11,s10,pp/h,x14/5,pd/i,x1/2,s9,x13/7,pg/e,s8,x11/5,pl/f,s8,x7/13,ph/p,x9/10,s13,x7/15,pf/k,x11/4,pd/o,x12/5,s12,x0/3,s15,x1/15,s4,x12/0,pb/i,x8/4,s9,x9/10,pm/e,x11/8,pa/g,x9/14,s4,pb/i,x6/10,s7,x5/4,pp/c,x1/13,s11,x11/9,pi/b,x10/8,pm/f,s13,x9/7,s2,x14/13,s11,pd/a,x6/4,s15,x11/13,s12,x5/7,pb/i,x6/4,s8,x14/2,s14,x13/12,s6,x3/9,s1,x4/11,ph/p,x14/9,pl/i,x8/13,pn/d,s1,pi/o,x5/10,pc/m,x14/4,s7,x10/13,pp/f,x15/2,s2,x13/5,s2,x1/14,pc/o,s7,x5/2,pm/g,x0/3,s9,x10/14,s2,x3/2,s15,x11/0,s4,x6/5,s11,x7/15,s9,x12/4,pb/k,x5/9,s2,x6/4,s4,x13/10,s6,x15/0,pg/j,x3/6,s9,x2/13,s14,x0/15,s7,x3/6,s9,pl/b,x1/10,s14,x15/13,s1,x11/1,s10,x4/10,s3,x12/6,s14,x10/14,po/c,x5/11,s4,x4/6,s2,x10/5,s6,x11/13,s11,x10/4,s6,x14/1,pb/p,x5/4,s8,x9/15,pe/a,x4/1,s12,x3/7,pi/m,x15/9,ph/o,x7/8,pi/g,x10/14,s5,x8/9,pa/f,x13/6,pi/g,s1,x0/2,s15,x3/12,s13,x1/7,pa/f,x8/9,po/b,x7/0,s1,x5/13,s4,x12/3,pf/e,s10,x13/9,po/g,x10/6,pf/c,x3/13,ph/j,s2,x5/8,s6,x13/3,s14,x10/6,s3,x1/4,pc/k,x7/8,s5,x15/11,pm/o,x0/10,pl/c,x15/9,pe/g,x5/4,ph/d,x1/3,pj/g,s4,x14/15,s14,x8/10,pi/p,x15/9,s6,x10/2,s14,x11/1,pd/f,x7/0,s10,po/k,x11/1,ph/j,x2/12,s5,x3/6,s12,x4/7,pb/c,s4,x3/14,s3,x5/12,s4,x11/8,s11,x7/13,s6,x9/8,s5,x5/0,s12,x15/10,s1,x9/5,s12,x3/15,s13,x14/7,s4,pg/e,x6/15,s3,pm/j,s9,x10/7,s8,x8/3,s11,x11/12,pn/h,x14/15,s4,x0/10,s11,x8/6,s13,pa/f,x5/9,s13,x2/6,pn/d,x12/1,s8,x3/0,s2,x5/15,s4,x9/10,pb/m,s6,pp/h,x6/0,s12,x15/13,s7,x14/6,s12,x2/5,s13,x4/8,s11,x10/7,pe/k,x5/13,s5,x6/14,pg/j,x15/4,s5,x2/6,s11,x9/8,s9,x3/2,s2,x11/9,pa/i,x8/10,s12,pk/o,x14/12,pb/h,x5/0,s5,x11/14,s14,x3/0,pf/g,x7/9,pb/h,x10/13,s15,x1/15,pi/e,x14/0,s13,x9/12,s15,x10/1,s7,x8/14,s2,x1/0,s12,x4/15,pd/p,x7/13,s8,x11/9,pl/k,x15/7,s14,x10/0,s9,x9/4,s10,x11/7,pf/g,x12/3,pd/o,x6/13,s10,x1/12,s14,x2/3,s8,x7/9,pm/b,x0/2,s6,x6/3,s10,x13/7,s3,x15/6,pf/h,s2,x4/0,pd/c,x14/13,s3,ph/j,x2/1,pm/i,x13/9,s5,x4/7,s11,x6/8,pc/g,x10/3,s14,x1/14,s1,x11/13,s4,x15/8,s14,x11/1,s4,x4/2,s7,x13/11,s8,x4/3,s15,x11/2,s6,x0/3,pe/l,x14/6,s2,x7/2,s14,x10/1,pj/c,x15/3,s2,pa/g,x13/1,s7,x12/3,s9,x10/6,s1,x8/15,s10,x13/2,pl/c,x8/0,po/n,x14/3,s9,pm/i,s9,x2/7,s8,x11/9,s9,x5/0,s9,x14/8,s14,x9/11,s7,x12/5,pj/o,x0/15,s10,x3/5,pp/d,x6/12,pn/i,x3/4,s9,x12/13,pb/k,x5/15,pd/p,x4/8,s1,x12/1,pi/f,x6/2,s8,x13/1,s12,x8/4,s9,x7/5,po/h,x10/4,s12,x3/2,s9,x6/4,s2,x11/3,s7,x0/1,s6,x5/4,s9,x13/9,s8,x10/7,s9,x11/12,pl/b,x1/8,s10,x7/2,s3,x14/8,pf/i,x0/2,pp/j,x4/1,pk/f,x8/15,po/p,x2/6,s9,x12/0,s12,x6/7,pg/l,x2/14,s14,x1/0,pa/c,x11/5,pb/n,x8/2,pm/d,x9/10,s1,x0/15,po/c,x5/9,pl/i,x12/13,pd/m,x11/6,s2,pf/n,x5/3,pg/l,x9/14,pe/m,x6/10,s14,x14/8,ph/i,x2/12,s8,pc/f,s12,x15/13,s12,x4/2,pm/j,x15/5,s13,x12/4,s9,x13/6,pk/p,x8/14,pc/d,s8,pn/k,x2/12,s14,x0/3,s8,x6/13,pc/o,x11/0,pe/g,x7/3,po/p,x6/2,pj/k,s8,x5/8,s14,x10/3,pd/l,s6,x9/2,s10,x4/3,s2,x2/6,s5,x12/13,s15,pk/i,x14/10,s11,x13/6,s14,x10/12,s8,x2/1,pc/o,x10/14,pg/b,x6/1,s14,x12/8,pp/l,s7,x11/9,pc/j,x7/4,s9,x3/8,pl/f,x14/6,s10,x2/1,s14,x5/9,pi/g,x8/6,ph/e,x1/13,s13,x11/8,pb/l,x9/3,s13,x1/0,pg/p,x10/5,po/l,x8/3,s5,x9/0,s12,pi/m,s12,x7/12,s12,x2/14,pd/j,x4/0,s1,x9/8,po/p,x15/5,s4,x2/10,s10,x14/9,s2,x1/0,s12,pb/i,s9,x9/10,s9,pj/e,x0/13,s10,x2/10,ph/p,x11/0,s4,x2/5,pf/o,x3/0,s13,x14/7,s12,x9/1,s14,x11/5,pa/d,x14/12,s2,x2/4,s4,x7/12,s14,x3/9,pf/b,x6/11,pp/o,x5/12,s10,x11/0,pf/h,x4/14,pg/j,x11/0,s14,x7/12,s9,x8/4,pc/d,x10/1,s1,x12/9,s3,x5/11,s7,x6/13,po/j,x10/14,s1,x7/1,s11,x6/15,s15,x8/13,s10,x3/10,pc/h,s11,pl/f,x1/9,s14,x6/8,pm/o,x1/5,pe/b,x15/4,pn/l,x1/7,s9,x13/10,pd/f,x1/11,pn/m,x9/15,s14,x11/1,s11,x14/5,pe/i,x1/3,pa/h,x12/8,s9,x11/1,s10,x9/6,s7,x14/4,pp/i,x10/12,s1,x0/14,pb/m,x4/12,pj/f,x9/15,s5,pc/i,x12/10,s15,pg/f,s12,pl/p,x3/11,pf/n,s9,x2/12,s8,x14/13,s11,x6/11,pg/l,x8/0,pe/k,x5/15,s15,x13/1,s6,x12/5,pl/j,x15/2,s14,x6/5,ph/i,s7,x11/10,pf/e,x4/0,s4,x2/3,pj/o,x6/15,s3,x14/13,pc/p,x11/9,s5,x8/1,pb/f,x7/0,s11,x4/15,s14,x13/7,s13,x14/0,s1,x6/3,pj/h,x14/4,pp/i,x7/3,s13,x11/14,s2,pn/f,x1/3,pl/j,x2/4,s6,x6/11,pi/e,x1/9,s4,x15/3,s12,x4/2,s14,x3/11,s7,x1/10,s8,x5/14,s8,x0/6,s5,x14/9,pm/g,s13,x2/1,s5,x3/15,pp/f,s13,ph/n,x1/10,pf/b,x9/15,pi/c,x7/1,s9,x12/8,s5,x15/9,pb/p,x7/2,s9,x8/0,s8,x5/13,pe/k,x2/11,pm/a,x10/12,s12,x7/3,po/c,s12,pe/b,x0/10,s3,x13/5,pf/i,x6/0,s6,x12/11,s1,x0/2,s8,x13/3,s9,x11/1,pl/h,x9/2,s2,x7/3,s1,x2/1,pf/p,x3/11,pn/o,s1,x2/6,s8,x12/14,s15,x0/2,pa/g,x14/11,s9,x15/10,s13,x14/8,pk/j,x13/9,s4,x10/5,pn/e,x13/12,s11,x8/4,pa/h,x11/1,po/l,x10/3,s9,x9/7,s12,x14/3,s5,x5/4,s6,x15/9,s6,x5/6,pn/p,x15/9,s8,x7/14,pc/h,x15/4,s6,x2/8,pa/g,x4/9,s6,x15/1,s8,pn/h,x10/8,pg/b,x15/6,pc/l,x7/10,s7,x5/9,s9,x8/14,s13,x9/7,s9,x14/11,s14,x4/0,s13,x6/3,pa/e,x4/5,s10,x1/15,s13,x3/12,s6,x2/13,s12,x7/9,s2,x13/4,ph/b,x14/10,s2,x15/7,pn/p,x0/8,s10,x10/5,s15,x13/0,s2,x15/1,s9,x13/11,s2,x3/9,s5,x13/0,ph/d,x14/12,pg/n,x9/6,pb/j,x10/5,pf/o,x3/15,s12,x9/6,s5,x3/1,s12,x10/2,s10,x14/8,pb/n,x0/2,s12,x7/12,pa/d,x6/4,s6,x9/11,s9,x12/0,s3,x4/9,s4,x13/5,pm/e,x8/14,s5,pi/j,x7/15,s1,pk/o,x3/6,s13,x7/10,s11,x9/2,pm/b,x7/4,s10,x0/12,po/j,x8/1,pf/k,x9/7,s6,pb/m,x13/12,ph/p,s3,x7/5,pg/j,x6/14,pi/d,s8,x10/2,s12,x14/12,s15,x6/2,pj/o,x4/10,pm/e,x0/11,s1,x13/1,pf/i,x0/2,s11,x10/4,s3,x0/8,s15,x12/1,s4,x6/3,s3,x12/5,s15,x2/13,s2,pn/p,x9/1,s10,x4/15,pe/o,x2/9,s8,x8/1,s7,x0/4,pn/h,s2,x13/15,s3,x2/14,s9,x5/8,pk/f,x9/2,s15,x12/11,pb/h,x9/4,pf/a,s14,x3/10,s13,x13/8,pe/j,x9/2,s3,x4/8,s5,x10/6,s2,x1/3,s3,x15/12,s2,x11/6,s5,x2/15,pp/f,x10/3,pd/k,s9,x11/9,s8,x10/1,s5,x6/13,pj/c,x1/2,s12,pg/k,x9/14,pi/d,x6/8,pk/f,s2,x15/14,s9,x12/4,pn/g,s1,x11/3,s10,x7/15,s8,x4/5,pa/j,x15/8,pd/l,x2/6,s3,x14/15,pg/o,x8/4,pa/m,x9/7,pn/e,x13/0,s6,x12/6,s14,x2/10,s14,x15/8,s3,pj/m,s3,x3/11,s8,x1/4,pg/k,x3/8,s15,x2/9,po/h,x1/14,s2,x9/15,s8,x5/14,pi/a,x3/15,pk/h,x11/7,pg/n,x4/8,po/i,x14/5,s14,x11/15,s5,x2/6,s12,x13/7,pa/j,x15/6,pl/e,x8/13,s5,x11/10,s15,x5/9,s15,x12/14,s10,x3/11,po/h,x6/8,s13,x0/9,pa/e,x10/11,pp/k,x0/12,s4,pe/a,x14/5,s5,x7/8,pf/h,s9,x14/13,s6,x8/2,s7,x6/9,s9,x7/13,s15,pl/n,x0/3,s2,x4/2,s10,x0/12,s1,x10/4,s2,x0/1,s14,x8/11,po/m,x7/14,s14,x11/3,s6,x4/9,s12,x14/12,s10,x7/11,s2,x6/15,s13,x0/14,pb/l,x4/7,s11,x6/2,s5,x8/0,pg/a,x9/10,s9,x1/12,s10,pi/f,x2/0,s8,x15/1,s8,x5/9,s14,x1/10,pc/h,x15/3,s10,x14/10,pl/i,x4/7,pp/d,x13/0,s14,x12/6,s14,x0/7,s9,x8/1,s7,x2/14,s3,x7/11,s6,x2/5,s6,ph/i,x7/12,s13,x15/2,pm/e,s2,x0/7,pa/n,x6/2,s11,x7/4,s3,x1/3,s15,x13/12,s14,x1/2,pf/j,x4/13,s13,x14/2,pi/n,x5/11,pe/k,x7/15,s9,x6/5,pi/l,x1/4,s1,x12/8,s12,x5/13,s6,x15/3,pp/g,x5/13,s5,pc/h,x6/8,s4,x0/12,po/e,x9/14,s15,x6/1,s6,x4/0,s3,x13/8,s4,x6/2,s11,x1/7,pa/l,x3/15,s8,x13/11,s7,x3/14,pc/k,x9/2,s2,x15/3,pl/g,x8/9,s2,pn/f,x2/5,s7,x15/3,s10,po/b,x9/12,s4,x5/3,pa/f,s3,pl/i,x6/11,s4,x1/5,pb/c,x7/6,s14,x15/10,s2,x8/4,s14,x13/14,s3,x0/15,s12,x13/5,s7,x10/8,s15,x7/3,s9,x5/12,pa/e,s11,x10/9,pc/i,x13/14,s13,x7/15,pe/g,s2,x3/6,pn/c,x7/0,s12,x1/11,s8,x8/7,s8,x0/13,pl/a,x8/5,pe/n,s10,pd/m,x6/9,s12,x1/14,s15,x3/5,pg/l,x7/11,s13,x0/6,s9,x15/5,s12,x8/6,pp/a,x14/1,s12,pe/i,x4/7,s10,po/h,x11/12,s13,x2/4,s4,x6/13,pa/l,s2,x3/11,s2,pb/d,x13/9,s4,x4/1,s1,x8/15,s10,x12/2,s5,x6/8,pc/h,x13/5,s6,x10/8,s12,x7/6,s8,x2/0,s9,x14/1,pg/p,x9/12,pj/h,x10/14,s8,x1/6,pp/c,x4/3,s2,x12/8,po/e,s4,x11/0,pi/d,x9/7,pm/e,x13/10,s14,x8/0,pa/n,x9/3,pb/p,x6/14,s14,x5/11,pl/f,x10/1,pn/i,x11/15,s13,x4/7,s13,x10/13,s9,x14/6,pk/g,x9/4,s9,x12/8,s13,x1/3,s6,x6/12,s8,x1/8,s7,x6/14,s15,pm/h,x15/8,s7,x12/4,pf/n,x15/13,pa/b,x12/14,s15,x6/8,s10,pn/c,x13/3,po/f,x1/6,pb/j,s1,x11/5,s3,x10/13,pe/o,s12,pj/h,x0/9,s12,x13/2,pk/d,s13,x10/15,s5,x8/1,s7,x15/11,s2,x4/14,s8,x10/3,s2,x0/6,s6,x14/12,pc/f,x7/10,pk/p,x11/4,s9,x7/14,s15,x1/9,po/f,x5/11,pd/e,x9/13,pn/i,x4/1,pk/m,x3/15,pl/i,x10/6,po/c,x7/1,pn/f,x12/14,s8,x7/2,s7,x14/0,s13,x7/5,s14,x1/15,s2,x5/6,s4,x2/8,s4,x13/12,s1,x10/5,s14,x6/1,po/h,x11/3,pe/n,x15/2,s15,x11/13,s1,x3/0,pg/m,x6/1,pe/a,x11/2,po/g,x9/7,s6,x4/14,pe/m,x2/3,pf/g,x15/7,s5,x11/0,s13,x4/13,pn/d,x6/15,s9,pb/o,x4/8,s7,x1/14,s9,x4/7,s6,x3/13,pi/c,x2/5,po/m,x12/15,pa/p,x13/8,s7,x9/4,pg/e,x8/6,s13,x1/15,pb/k,x9/2,po/a,s8,pl/e,x11/10,s2,pi/j,x9/3,s6,x10/15,s9,x13/4,pb/n,x1/14,pl/e,s15,x6/12,pn/a,x13/5,po/l,s1,x8/12,s5,x7/1,pp/h,x9/2,pl/e,x10/11,s13,x3/9,pc/g,x5/11,s8,x9/14,s5,x3/12,pn/p,x13/14,pk/e,x7/4,pl/a,x12/5,pf/n,x11/7,s15,x14/0,s7,x2/9,s3,pb/p,x12/8,s13,x10/2,s8,x8/14,ph/c,x10/5,pm/f,x14/2,pg/h,x5/3,s10,x15/12,pi/m,x10/3,pd/f,s6,x11/9,s1,x3/14,s13,ph/a,x6/5,s4,pj/l,x12/0,s10,x7/9,s2,x15/4,pp/a,x5/13,s15,x3/0,pk/d,x4/12,pe/b,x2/9,pd/g,x0/13,s2,x1/11,s3,x7/14,s1,x8/9,s15,x1/7,pi/n,x0/15,s5,x10/7,s5,pk/b,x8/0,s11,x1/13,s4,x3/10,pp/d,x14/2,s4,x3/9,s15,x1/12,s13,x14/8,s4,x7/0,s5,x3/14,pa/e,x7/12,s15,x14/1,pg/i,x6/10,s7,x4/7,s7,x2/9,s2,x13/12,pm/d,s14,pi/o,x15/0,s5,x9/12,s2,pl/n,x8/2,pa/o,x11/5,pm/p,s2,x4/9,pg/o,x15/13,pa/h,x3/6,pb/g,x14/4,s12,x1/10,pl/n,x7/4,s8,x12/9,pi/f,x3/7,s7,x0/14,s10,x1/8,s4,x11/9,pg/p,x12/4,s4,x9/7,s3,x6/1,s1,x5/14,s1,x4/0,po/k,x3/5,pj/l,x4/7,s4,x12/13,s7,x9/6,s5,x11/5,po/n,x9/7,s2,x13/14,s5,x8/11,s7,x7/0,s7,x1/13,s3,x6/9,pj/f,x13/3,pa/i,x7/14,pe/m,x3/15,s1,x10/13,s9,ph/o,x0/4,s9,x14/6,s5,x0/13,s1,x15/4,s11,x9/7,s7,x8/14,pf/m,x1/13,s7,x15/9,pe/g,s3,x7/10,s1,x11/4,pl/n,s11,x0/14,pm/o,s7,x4/9,s14,x2/12,pj/l,x1/13,s4,x2/9,pf/h,s5,x7/3,s13,x11/10,s15,x15/3,pa/o,s15,x1/9,pj/l,x14/3,s8,pc/k,x15/4,s11,x12/5,pp/d,s9,x13/14,po/f,x8/10,s12,x12/7,pd/m,s3,x2/13,pb/l,x9/5,pf/a,x7/8,s5,pn/i,x6/15,s14,x11/12,s7,x15/10,s7,x12/13,pa/j,x3/10,s5,x1/4,pe/c,x7/0,s8,x9/14,pb/m,x15/7,pc/l,x14/4,pg/b,x6/10,s3,x11/13,pe/j,x8/6,pl/h,s8,x3/10,s3,x13/2,s8,x11/5,s4,x15/8,pp/n,x2/9,s7,x13/5,s6,x12/6,pa/l,x3/10,pd/i,x4/6,pn/f,x9/8,pe/b,x6/3,s5,pf/c,x9/11,s3,pn/b,x4/14,pd/l,x11/8,s14,x0/9,s1,x4/1,pf/e,x3/2,pi/m,x11/15,pk/g,x12/14,pb/n,x10/8,s8,x2/3,s14,x14/12,s12,pd/e,x11/1,s6,x8/4,s6,x2/3,s6,x6/14,s15,x9/7,pa/m,x0/10,pk/d,s4,x14/4,s2,x12/0,pn/a,x3/13,pg/f,x14/15,pj/a,x12/0,s9,x7/6,s2,x2/3,s2,x7/6,pg/b,x0/12,s15,x4/2,s12,x15/7,s1,x6/2,s12,x0/12,s13,po/e,x2/3,s7,x13/9,s5,x6/14,s11,x11/2,s14,x7/15,s3,pk/h,x8/13,s3,x6/1,s13,x15/0,s6,x10/13,s14,x7/9,s14,pl/f,x13/6,s12,x4/11,s10,x12/8,pg/c,x2/9,s15,x3/1,s3,pa/k,x6/0,s10,x13/9,ph/l,x14/5,s3,x3/1,s6,x5/6,pj/a,x1/7,s2,x15/11,s4,x7/0,ph/d,x9/12,pl/e,x2/5,pn/c,x14/8,s3,x0/4,s5,x2/12,pe/j,x0/15,ph/p,x3/10,s11,pm/b,x4/1,s6,x11/3,s11,x0/7,s1,x5/12,pi/l,x2/7,s9,x5/8,s10,x14/3,s11,x13/4,s8,x15/0,po/k,x5/13,pl/m,x4/7,s3,x3/10,pg/o,x6/9,s5,x5/11,s13,x10/0,pc/d,s3,x12/6,pk/e,x2/11,s4,x9/4,s2,x13/2,s13,x0/14,s5,x6/5,pf/a,x14/11,s12,x15/0,s15,x13/11,s12,x1/8,pe/j,x7/6,s8,x5/1,s11,x7/9,s8,x4/5,s10,x10/14,s2,pl/p,x4/15,s9,x0/13,pc/k,x12/5,s8,x8/10,pe/a,x3/7,pc/f,x8/10,pm/b,x5/0,s9,x2/8,s3,x14/11,pa/d,x0/15,s9,x1/5,pj/i,x2/7,s5,x10/4,pf/o,x15/14,s1,x8/9,pn/a,x5/1,pk/j,x3/12,ph/l,x7/15,pf/g,x1/12,s4,x13/6,s10,x8/1,s14,x13/6,s4,pi/d,x12/9,s4,x14/5,s6,x3/10,s8,pp/n,x0/9,s5,x1/11,s9,x0/15,pa/k,x11/6,pj/b,x15/3,s15,x5/4,s13,x13/10,s7,x6/9,s15,x5/11,s4,x7/10,s7,x8/12,pk/p,s5,x13/7,pl/j,s3,x15/9,s1,x6/8,pf/i,x3/4,s7,x7/8,s1,x12/2,s5,x1/14,pj/m,x3/5,pp/n,x6/7,s14,x3/12,ph/c,x13/0,s10,x4/3,pd/f,x9/1,pg/a,x5/8,s4,x12/15,pd/k,x1/2,s14,x0/10,pa/e,x2/4,s13,x1/15,pi/k,x3/14,s15,x11/4,s4,x14/12,s12,ph/l,x2/7,pn/d,x5/12,pg/k,s12,x10/0,s6,x5/12,s4,pn/c,x7/10,s3,x12/15,pd/p,x8/9,s15,x5/14,pk/a,x4/13,pb/d,x9/2,s6,pi/l,x10/11,pb/n,x14/12,s15,x8/1,pl/h,x2/12,s9,pf/c,x8/5,s2,x4/10,s11,x12/13,s12,ph/g,s1,x1/10,s13,x2/8,pe/b,x10/9,pg/l,x15/14,pj/h,x13/8,pe/n,s4,x1/10,s13,pk/o,x0/4,pn/c,x12/11,pj/m,x7/1,pp/l,x6/10,pj/o,x0/11,s5,x15/12,pn/a,x2/6,s9,x9/14,s7,pk/o,x7/6,pf/b,x15/5,pj/n,x8/2,s3,x7/11,s4,x9/14,pk/h,x1/4,s8,x9/10,po/m,x12/8,s13,x15/9,s8,x2/8,s1,x14/11,s1,x5/8,s15,pe/g,s14,x15/3,s2,pd/m,x4/12,s4,x15/13,pb/l,x11/6,s6,x0/3,s2,x13/6,s3,x1/3,pa/k,x15/13,s14,x4/7,po/e,x14/13,s15,x11/10,s12,x12/5,pc/a,x11/13,pk/e,x6/12,pf/n,x10/8,pb/l,x15/9,s10,po/i,x14/8,s6,x2/13,s1,x15/10,pj/h,x9/6,s15,x4/7,pn/i,x12/2,s5,x15/9,pf/l,x3/13,s9,x11/15,s9,x13/12,s6,x1/15,pa/g,x0/10,s4,x15/11,s1,x10/3,s12,x4/6,s1,x15/14,s11,x1/3,s6,x7/5,s2,x0/4,po/l,x7/5,s8,x8/2,pk/m,x10/12,po/n,x9/7,s13,x13/14,s13,x4/7,pe/b,s1,x3/10,s6,x6/15,s12,x8/3,s1,x2/9,s3,x12/11,s6,x5/9,s3,x4/12,pn/j,x5/3,s2,x9/14,pi/g,x13/8,s8,x4/12,s1,x13/9,pd/l,x7/0,s8,x13/12,pm/o,x2/10,s6,x3/12,pg/c,x7/0,pj/b,x1/11,pi/m,x7/4,pb/d,x8/1,po/g,x13/11,s12,x2/9,pc/j,x7/4,s15,x10/2,pk/g,s10,x1/0,po/c,x8/11,pg/d,s3,x6/1,pl/a,x13/9,s2,x2/0,pc/j,x6/9,s1,x4/15,s12,x10/7,pl/f,x0/13,pi/n,x14/5,s6,x8/4,s4,x6/15,s1,x1/4,pj/f,x9/13,s13,x14/6,ph/o,x2/11,pe/d,x5/7,s10,pm/b,s7,x11/2,pi/o,x5/12,pa/n,x7/15,s8,x10/11,s2,x5/7,pc/h,x10/0,s7,x7/2,s8,x11/14,s11,x6/9,s4,x12/10,pd/k,s8,x8/14,s4,x4/6,s13,x15/14,s14,x8/11,pi/n,x4/14,pe/m,x3/11,s6,x13/1,s9,x15/3,s10,x2/0,pb/k,x5/1,pl/n,x12/11,pb/h,x4/9,pm/e,x6/13,s14,x11/4,s14,x8/7,s4,x12/13,s10,x6/1,pb/p,x15/3,s5,x14/0,s5,pf/c,s4,x13/12,s14,x5/2,s3,x13/1,s12,x14/8,s14,x3/1,s14,x5/0,s10,x10/6,s4,x1/5,s3,x9/10,s8,pm/j,x14/15,pp/b,x12/0,s6,x5/9,s7,x2/8,s14,x1/13,s9,x2/4,s6,x9/7,ph/l,x11/5,s6,pb/o,x8/13,s10,x4/10,s5,x13/6,s6,x9/1,s10,x3/12,s10,x4/7,s11,x6/15,ph/n,x11/12,pj/a,x13/7,s7,pf/n,x12/6,pm/b,x14/11,s1,x7/9,pl/f,x15/8,pd/g,x11/3,s10,x1/0,s2,x10/3,s1,x15/1,pb/f,x3/2,s13,x13/9,pj/k,x3/11,s7,x10/5,pp/c,x2/4,s10,x1/11,pj/b,x8/6,pg/p,x7/14,s4,x15/6,pa/i,x10/8,pd/h,x5/14,s14,x11/0,pf/l,x13/5,s11,x3/8,pm/d,s11,x4/9,po/k,x0/3,pj/e,x15/11,s10,x12/13,s7,x4/2,pl/k,x7/14,pa/n,x3/0,pj/b,x14/11,pp/a,x0/4,ph/m,s10,x14/9,pa/l,x1/8,po/n,x9/14,s10,x6/5,s11,x2/10,pe/p,x3/9,s10,x6/2,s3,pl/m,x5/10,s3,pn/a,x15/1,s2,x6/10,pd/b,x11/8,pa/p,x5/3,pb/h,x2/12,s8,x7/14,s6,x3/8,pg/e,x6/0,s4,x13/15,pj/k,x0/3,s5,x11/5,pi/b,x9/6,s7,x12/11,pe/d,x6/14,ph/p,x7/4,s4,x6/8,pi/m,x0/2,s5,pb/l,x1/3,s11,x7/6,pp/f,x8/15,s14,x11/5,s4,x12/14,pk/n,x11/9,s6,x3/5,ph/g,x2/1,pi/o,x8/10,s9,x15/13,s2,x2/7,s9,x1/8,s9,pg/k,x15/4,pl/b,x14/8,s3,x13/1,pn/m,x12/11,s5,x4/3,pe/c,s7,x5/14,s11,x12/4,s7,x11/0,s10,x14/1,s11,x8/15,pg/h,x9/13,s1,x5/8,pn/k,x4/12,pm/l,x14/6,s11,x4/15,s5,x14/13,pj/i,s12,x7/12,pc/a,s7,x5/4,pe/d,x1/2,s10,x8/7,s9,x15/11,pf/b,x7/12,s8,x8/2,s4,x0/11,s15,x6/5,s12,x15/1,pa/m,x5/8,s13,pl/h,x11/7,s10,x12/8,s2,x10/11,s13,x1/4,s1,pc/g,x11/10,s11,pi/l,x8/4,s14,x0/13,pb/n,x10/8,s3,pi/a,x3/12,s15,x15/0,po/h,x11/4,pm/n,s13,x15/13,s11,x8/9,s5,x5/14,s7,x6/10,s13,x12/15,s6,x4/11,s10,x13/6,pa/h,x12/1,pb/l,s11,x3/9,s7,x10/14,pc/d,x9/11,pm/k,x0/14,s1,x8/2,s15,x0/12,s13,x1/7,s9,x3/11,s13,x8/10,s3,x14/6,s11,x15/8,s10,x5/2,pf/h,x15/0,s15,x8/2,s13,x7/3,s10,x14/15,pa/k,x9/2,s4,x0/5,pg/b,s11,x2/3,s10,pd/m,x0/14,pk/l,s8,x1/9,s3,x13/0,s8,x3/8,pg/n,x5/6,s11,x7/1,s2,x5/8,pd/c,x12/14,ph/j,x11/7,s7,x3/14,pf/i,x2/0,s2,x6/7,pm/d,x3/13,pe/k,x6/10,pf/a,x13/3,pm/b,x8/5,s12,x7/10,s15,x8/12,pd/a,x14/10,s14,x2/12,pe/c,x1/7,pm/i,x4/11,s3,x6/0,pf/j,x10/15,s15,x0/3,pb/d,x4/14,s5,x11/8,s8,x10/5,pm/c,x1/12,s8,x14/2,s6,x0/9,s12,x15/7,s7,x1/11,s12,x13/5,s10,x15/1,po/h,x4/13,pe/k,x3/1,pj/a,x6/14,s10,pg/f,x10/7,s7,x1/5,s8,x15/0,s11,x1/6,pi/m,x0/15,s6,x8/9,pa/l,x3/6,pp/o,x14/8,s2,x15/7,s7,pa/h,x2/1,s10,pd/n,x15/7,s4,x11/0,pj/f,x12/9,s5,x5/11,s13,x4/1,pd/c,x2/14,s14,x5/11,s10,x15/10,pe/k,x9/14,pp/a,s8,x6/10,s10,x0/11,s11,x15/8,pd/c,x9/10,s15,x5/2,s13,x13/0,s7,pj/e,x1/14,pk/p,x13/8,pm/c,x11/3,pk/l,x5/13,pp/c,x12/8,s15,x6/11,pg/h,x14/15,s2,x6/11,pj/c,s10,x0/13,s13,x6/15,s2,x14/7,s1,x0/5,pk/d,x4/14,pg/j,x9/8,s1,x3/12,s7,x13/11,s11,x14/4,s3,ph/o,x7/13,s1,x9/0,s10,x15/1,s12,x13/5,pc/l,x11/6,s7,x8/0,s13,x2/9,s9,x4/3,s8,x1/15,s9,pf/o,x7/10,s1,x5/11,s2,x15/8,pk/m,x5/7,pb/n,x3/4,s15,x8/15,ph/i,x9/5,s7,pn/m,x11/12,pc/b,x3/13,pd/i,x4/8,pa/n,x5/0,s12,x3/9,pi/b,x1/7,s9,x4/2,pn/a,x8/3,s3,x14/7,s2,x13/15,s13,x0/10,pi/b,x11/7,s1,x6/5,pf/a,x0/4,pm/e,x3/1,s10,x12/6,s8,x13/1,s10,x0/3,pl/f,x15/13,pd/m,s10,x4/12,s14,x3/10,s9,x0/7,s15,x6/4,pi/l,x11/2,pn/p,x15/10,po/d,x4/14,s9,x3/0,s5,x14/9,s12,x13/10,pj/l,x15/1,s7,pf/b,x8/0,pa/c,x13/5,s6,x9/11,s10,x2/5,s6,x9/8,s1,x0/7,s2,x3/8,s15,x9/11,pd/p,x4/3,s10,x0/8,s11,x7/2,pi/j,x3/0,pp/g,x1/15,s10,x13/14,pf/j,x10/8,pa/d,x2/11,pn/f,x13/7,s14,pi/m,x5/12,pp/n,x7/14,s10,x5/4,pc/m,x8/6,s2,x10/3,s3,x13/5,s15,x6/1,s1,x5/7,s10,x11/13,s10,x6/3,pe/o,x0/13,s4,x4/5,s1,pp/j,x8/2,s2,x14/0,s2,x13/8,s10,x4/7,pe/b,s4,x3/10,pn/m,x9/5,s15,x2/3,pf/h,x11/10,pp/o,x14/3,s2,x7/5,s11,x10/15,s2,x11/14,s2,x1/6,s13,x12/7,s13,x1/8,s1,x10/14,s9,x9/3,s4,x4/14,s3,x6/15,pa/h,x1/9,s5,x5/12,pb/m,x9/14,s9,x1/0,s14,x9/4,po/j,x7/8,s10,pn/f,x13/9,s13,x7/2,pp/e,x10/5,pg/l,x15/12,s4,pa/o,x7/3,pe/k,x2/13,s7,x11/10,pa/b,s5,x9/0,s10,x13/14,pn/j,x4/3,pg/l,x8/5,pa/j,s11,x15/10,po/g,x1/7,s10,x12/10,s1,x3/5,s2,x2/7,s7,x15/4,ph/c,x0/1,s5,x11/4,pf/m,x14/5,pa/p,x4/10,s1,x11/0,s15,x2/10,pe/b,x4/12,s9,x0/10,pn/k,x1/14,s10,x11/3,s7,x1/5,s13,x11/6,s7,x8/2,pi/g,x15/1,s4,x8/0,s10,po/k,x5/6,s5,x4/13,s11,x10/2,pc/i,x7/14,s7,x12/6,pj/d,x14/10,pb/o,x15/4,pe/f,x7/11,s11,x2/1,pn/o,x0/4,s5,x5/14,pk/a,x10/13,s7,x8/11,pd/m,x15/5,s6,x6/9,s14,x1/11,pg/j,x12/8,s4,pl/i,x3/13,s13,x2/8,s12,x5/15,pk/p,x3/9,pl/b,x10/4,s10,x5/1,s12,x2/8,s3,x5/9,s3,x15/12,s9,x5/10,s10,x12/1,s1,x5/10,pf/h,x13/0,po/d,x6/3,s8,x8/4,s13,x1/15,s11,x6/14,s1,x15/9,s11,x13/12,pa/j,x9/10,s15,x2/7,s4,x11/9,s10,x12/5,s12,pm/n,x8/6,s12,x7/9,s7,x3/10,s4,x6/7,ph/e,x11/0,s12,x2/15,s5,x5/11,s13,x13/7,pb/c,x14/6,pe/i,x0/4,s9,x1/12,s1,x14/9,pn/f,x15/7,s1,x4/9,s11,x6/15,s6,x1/13,s2,x12/10,s3,x7/9,s8,x1/6,s1,x2/5,pe/b,x1/6,s15,x3/12,pl/g,x5/10,pe/f,x8/2,s6,x1/4,pb/p,x13/0,pf/k,s9,x7/4,s5,x3/1,pp/a,x6/10,s2,x13/0,s13,x12/9,s2,x0/11,s7,x12/10,s2,x5/6,pi/l,x3/4,pk/n,x12/0,pl/p,x7/3,s10,x11/0,s12,x9/15,pi/g,x10/6,pl/j,x0/5,pi/a,x8/1,s6,x7/11,s9,x13/14,s1,pk/g,x7/10,pl/n,x9/2,s11,x8/7,ph/k,x3/1,pm/c,s10,x11/12,s5,x13/4,s8,x5/15,pj/o,s10,x1/0,s1,x15/10,s6,x13/6,pd/e,x7/11,s15,x0/3,pm/p,x7/4,s4,x12/1,s8,x2/10,s8,x12/15,s3,x5/9,pb/a,s4,x1/2,po/m,x11/4,s2,x13/6,s1,x12/8,s8,x13/7,pf/l,x9/10,pa/d,x1/15,pi/g,x7/6,s2,x15/1,s7,x10/8,pb/e,s2,x12/2,s8,x7/8,s14,x11/15,s1,x13/3,s9,x5/8,pj/a,x9/3,s8,x6/13,pn/c,s5,x5/9,pi/l,s7,x6/7,pm/p,x14/9,s2,x6/5,s9,x1/13,pj/g,x15/12,s5,x5/13,pp/i,x15/3,pe/b,s7,x2/14,ph/d,x15/11,pc/j,x1/12,pp/n,s14,x0/8,s6,x7/3,pi/k,x2/13,ph/n,x14/4,s4,x3/6,pg/l,x7/5,s3,x15/4,s7,x13/10,pi/p,s15,x5/0,s14,x15/11,s4,pd/l,x5/10,s9,x12/3,s8,x1/5,s5,x2/3,pm/c,s14,x12/11,pg/i,x5/13,s15,x12/1,pm/b,x0/10,s12,x8/4,pa/p,x12/3,pj/e,x9/1,ph/m,s13,x6/7,pa/b,x15/13,s7,x11/7,s10,x14/4,s14,pi/o,x7/10,pm/d,x4/15,pp/g,x10/11,pj/b,x15/8,s5,x6/0,s7,x7/11,pp/c,x5/14,s8,x4/12,pl/o,x14/5,s3,x6/9,pn/g,x15/3,s2,x0/1,s3,x4/10,s4,x13/15,s3,x14/1,s5,x6/15,pe/f,x5/0,s12,pd/l,x10/3,s4,x5/15,s9,x11/2,s12,x6/4,s14,x9/7,ph/c,x8/5,s2,x1/0,s4,x11/7,pp/b,x10/4,pk/n,x13/3,s11,x11/14,pi/e,x3/13,s3,x2/0,po/m,x8/7,s8,x14/5,pg/p,x2/11,s6,x14/15,pm/a,x1/12,pp/o,x13/11,s5,x9/5,s11,x7/14,pf/b,x13/9,s11,x5/11,s4,x6/12,s14,x11/3,s13,x4/8,s5,x9/0,pc/k,x7/12,pj/m,s13,x13/15,s4,x10/12,s13,x11/5,ph/o,x10/12,s7,pf/k,x0/11,s2,x14/2,s13,x9/12,s4,x15/10,pd/g,x5/8,pf/n,x9/15,pa/h,x8/12,pb/n,x14/5,pi/a,x10/4,s6,x0/9,s9,x14/4,pp/k,x2/11,s13,x0/15,s6,x2/14,s3,x13/3,s10,x4/8,s7,x10/1,s1,pf/g,s15,x2/13,s8,x3/12,pp/i,s8,po/a,x4/11,s8,x8/13,pp/d,x14/11,s9,x13/10,s8,x4/12,s10,x1/5,pf/g,x10/11,pi/e,x8/4,s7,x7/11,pj/o,x9/5,s7,pp/b,s3,x1/8,s11,x0/10,s4,x12/9,pe/l,s3,pc/j,x0/8,s10,x4/12,s14,x5/14,s4,x7/11,s14,pf/k,x10/8,s13,x13/5,s8,x8/12,s5,x11/13,pa/c,x7/12,s14,x6/8,s5,x7/2,s14,x9/3,pn/g,s6,x13/6,pi/e,x11/3,pj/a,x5/2,s2,x15/13,s11,x6/12,s2,x10/3,pg/l,x5/0,s4,x1/2,s1,x15/4,pf/n,s10,x12/6,s3,x1/11,s13,x10/13,s14,x3/8,s10,x4/13,pk/m,x10/0,s7,x15/14,pe/n,x12/1,s2,x4/14,s14,x11/7,s14,x15/9,s14,x14/11,s1,x0/3,s13,x4/12,s8,x0/7,s9,x3/1,s1,x6/14,s11,pi/c,x1/8,pk/a,x2/14,s9,x11/10,pl/c,x5/15,pf/d,x1/13,pm/e,x2/12,s10,x5/10,po/g,x7/11,s5,x8/2,s5,pp/j,x3/4,s10,x11/0,pf/b,x12/2,s13,x9/15,pe/k,x11/12,pb/i,x1/6,po/c,x3/11,pj/b,x14/0,pm/c,x3/4,ph/i,x7/12,s7,x5/6,s1,x1/0,pc/k,x6/4,s3,x12/9,pd/l,x7/1,s2,x13/3,s1,x11/2,s15,x14/9,s5,x1/11,pp/f,x12/2,pi/d,x14/9,po/m,x4/12,s7,x11/9,pn/a,s4,pm/l,x4/13,pi/n,x8/10,po/m,x5/13,pd/p,x12/7,s14,x5/14,s11,x3/6,s11,x10/14,s10,x9/12,s6,x6/4,pj/m,s12,x13/8,s13,x0/14,pb/o,x11/5,pk/h,x10/1,s10,x11/8,s12,x12/9,s4,x10/5,s14,x2/8,pn/o,x4/14,s4,x9/1,pe/f,s9,x2/13,s7,x15/11,pc/i,x3/7,s13,x4/2,s4,x7/10,s9,x3/11,s14,x5/8,s15,x7/11,pd/g,x13/6,s3,x12/0,s6,pi/c,x6/7,ph/m,x13/1,pk/e,s15,pa/g,x14/15,po/j,x5/11,s15,x9/14,s9,x5/12,pi/e,x10/3,pn/o,x11/1,pi/c,x7/5,s6,x3/9,s14,x15/13,pk/a,x6/5,s7,x14/15,s10,x4/5,pg/i,x2/9,s1,x3/6,pl/m,x10/14,s9,x4/15,s15,pp/o,x9/7,pc/d,x12/3,s7,pe/n,x13/4,s15,x3/6,ph/g,x5/10,pf/n,x11/12,s11,x4/15,po/j,x0/14,pi/d,x6/3,pe/h,s6,x15/2,s8,x5/6,s7,x14/12,pm/c,x10/13,po/l,s10,x4/15,pd/e,s1,x6/11,s8,x9/3,pl/o,x4/7,s14,x11/6,pc/k,x2/7,s11,x1/14,s5,x2/15,s10,x9/5,s3,pa/b,x0/4,pg/m,x13/3,s10,x14/11,s15,x0/4,pc/h,x3/7,s6,x6/1,pe/a,x2/15,s15,x5/0,pi/p,x11/12,po/g,x6/15,pa/j,x3/0,s14,x11/14,pk/p,x1/6,s12,x4/12,pm/g,x3/11,pb/a,x1/7,s3,x14/0,s6,x8/3,pe/n,x13/14,s12,x12/3,s4,x14/4,s11,x3/9,s7,x6/7,pk/o,x2/14,s13,x11/7,pn/d,x10/3,pf/b,x4/7,s10,pc/m,s6,x5/2,s13,x3/0,s7,x6/9,pd/n,s9,x10/1,s11,x12/15,s9,x10/14,pe/o,x0/12,s1,x7/13,pg/i,x1/5,s12,x9/15,pp/k,x13/10,ph/m,x11/9,pc/o,x15/6,pa/h,x12/11,s5,x13/2,pe/c,x0/15,s2,x6/3,s8,x4/15,s14,x2/12,s3,x1/13,s5,x10/4,s10,x12/6,s14,x5/4,pl/d,x13/7,s12,x5/9,s1,x2/10,s15,x14/15,ph/c,s14,x5/8,s4,x15/11,pa/b,x0/1,s3,x2/4,pe/l,x5/12,s2,x13/11,pj/p,x6/3,s2,x4/12,s7,pg/k,x9/10,s9,x7/5,s6,x6/12,s6,x4/1,s2,x7/6,s6,x9/13,s3,x15/7,pd/m,x6/14,s1,x0/10,pa/j,x8/3,s12,x5/0,s10,x6/1,pb/h,x3/8,pj/m,x15/13,pf/a,x7/9,s5,x3/5,pm/p,x12/11,s6,x15/13,s7,pn/j,x11/8,pc/e,x10/14,pn/h,x8/7,pj/k,x13/15,s15,x6/7,pg/f,x1/0,pb/l,x13/7,s10,x10/1,pk/n,x7/13,s10,pd/f,x5/9,pn/l,x10/8,pd/c,x0/2,pf/l,s4,x3/13,s11,x8/10,pk/i,x4/13,ph/j,x8/10,pb/e,x2/12,s5,x6/9,s8,x1/3,pc/n,x8/6,pd/g,x9/7,s7,pa/l,x13/4,s15,x5/2,s5,x9/10,pb/o,x3/7,pn/g,x10/6,pa/d,x13/4,s9,x2/11,s3,x15/3,s7,x13/11,s13,x0/8,pg/l,x9/4,s15,x10/1,s3,x14/0,pa/j,x1/8,s14,x3/11,s15,x2/15,s6,x4/5,pl/e,x8/6,s8,pk/o,x1/2,s6,x7/6,s6,pg/d,x0/11,pp/e,x4/14,s5,x11/10,pj/f,x4/2,pe/d,x8/13,s1,x7/14,s7,x12/6,s12,x13/7,s5,x10/1,s3,x12/8,s14,x13/9,pc/b,x14/8,s6,x4/0,s5,x6/9,pm/a,s1,x1/2,pc/g,x14/12,s8,x3/10,pn/b,x0/4,s1,x5/2,pl/f,x6/3,ph/o,x14/2,pl/p,s12,x1/7,s14,x14/10,pe/j,s14,x8/5,pc/n,x9/13,pi/a,x4/2,pb/m,x14/12,pe/n,x2/9,s8,x13/15,pj/f,s4,x6/3,pb/l,x9/2,s8,pc/f,x7/12,s3,x3/8,pl/h,x9/0,s4,x15/6,s13,po/g,x5/14,s5,x13/3,s9,x0/11,pl/c,x8/2,s1,x1/9,s13,x3/11,po/a,x2/5,s14,x7/0,s1,x8/11,s1,x14/4,s11,x1/5,s8,x15/12,s3,x0/9,s4,x8/12,pf/i,x10/9,pn/b,s4,x12/15,pk/c,x3/2,s11,pd/b,x4/5,s14,x7/11,pl/n,s15,x4/9,s8,x7/1,s2,x0/15,pb/g,x5/9,pd/i,x15/3,s15,pl/c,x5/4,s2,x13/11,s5,x6/9,pe/a,x13/12,pp/h,x14/9,pk/i,x8/5,s14,pa/o,x15/0,pg/d,x13/12,s12,pe/i,s11,x4/2,pd/c,s3,x12/10,pk/f,x7/6,pn/e,s10,x11/10,s6,x1/2,pi/c,x12/8,po/e,x9/6,pm/i,x0/5,s5,x3/1,pp/h,x6/9,pk/e,x8/5,pn/o,x15/9,pb/f,x4/7,s1,x3/0,pi/j,x7/14,pk/p,x1/9,pb/c,x7/13,s2,x9/8,s15,x6/5,pf/n,x12/8,pl/a,x14/3,s12,x5/8,s3,x11/9,s10,x2/15,s11,x4/8,pm/e,x15/14,pi/k,x7/11,s3,x8/4,pc/d,x1/9,s13,x12/14,pi/p,x11/4,s6,x1/8,pl/g,s3,po/j,s12,x10/15,s4,x6/7,s8,x14/3,pm/k,x8/11,pf/c,x4/14,pn/k,x5/0,s3,x10/13,s6,x0/8,s11,x1/3,pl/b,x13/7,s5,x6/4,s11,x10/3,s11,x14/5,s8,x13/11,pa/p,x2/12,pl/m,x3/8,s12,x12/11,s2,x6/4,pe/h,x7/11,pa/i,x14/12,s13,x10/13,s5,x4/6,s3,x2/7,s2,x11/3,pm/l,x13/12,s15,x9/14,pf/n,x2/7,s6,x9/8,pi/p,x6/4,pa/h,x3/14,po/e,x0/1,s10,x5/14,s14,x0/15,s11,x14/9,s10,x1/2,s13,pm/p,x4/11,s3,po/l,x8/1,s7,x13/12,pc/k,x6/2,s11,x10/13,po/l,s6,x14/7,pn/b,x2/12,s14,x5/6,pf/o,x13/14,pm/h,x8/3,pg/b,x12/14,s10,x13/1,s8,x14/15,pd/a,x13/5,s7,pc/f,x8/6,s14,pe/d,x9/15,pp/o,x13/12,pl/a,x14/7,s10,x2/12,s2,x11/7,s13,x0/8,s6,x10/1,pp/e,s10,x4/7,s9,x11/15,s1,x4/5,pk/l,s1,x10/14,s5,x0/1,s7,x9/10,ph/n,x2/14,s9,x15/12,pj/f,x9/6,s10,x4/10,pb/m,x0/7,pc/g,x14/13,s10,x9/6,s8,x5/12,s14,pe/m,x1/15,s8,x8/13,s9,x11/1,pg/c,x10/2,pb/l,x15/0,s15,x4/6,pm/f,x7/13,s9,x8/15,pi/p,s4,x3/2,po/j,x11/1,s8,x6/4,ph/i,x14/0,pa/e,s6,x2/1,ph/p,x10/8,s4,po/g,x5/15,pk/p,x2/10,s6,x0/15,pf/d,s10,x2/14,pl/m,s6,pj/c,s8,x6/3,pn/o,x8/12,pm/h,x2/9,pb/c,x4/14,pp/d,x8/5,s13,x2/6,pi/j,x7/13,s14,pe/d,x14/3,s13,pb/p,s9,x6/0,pn/d,x9/5,pl/p,x3/7,pd/h,x14/11,po/n,x15/0,s10,x4/1,s11,x13/12,s10,x5/10,s14,x1/15,s15,x12/10,pf/d,s1,x6/0,s6,x15/4,s7,x5/11,s6,x8/4,s12,x5/3,s2,x8/1,s10,x9/15,s3,ph/o,x2/13,pn/l,x9/12,s8,x3/1,s2,x4/13,pa/e,x11/12,s6,x10/0,pm/o,x9/4,pn/f,x15/3,s2,x10/14,pg/h,x3/9,pp/k,x7/5,s1,x11/15,pc/l,x14/4,s13,x8/3,s6,x10/6,s15,x13/4,s15,pm/o,x5/3,s15,x6/12,s5,x15/4,pa/p,x14/7,s10,ph/c,x4/1,s13,pj/k,x0/8,s5,x7/9,s9,x10/5,s7,x12/8,s1,x6/15,s3,x8/4,pb/m,x2/1,pf/h,x14/4,pa/n,x10/5,pl/b,x12/14,s4,x10/9,pp/j,x5/1,s11,x2/11,s11,pe/o,x4/15,pj/h,x8/10,s3,x1/12,pc/i,x3/7,s2,x4/6,s4,x9/13,pp/h,x1/15,pm/f,x8/5,pg/i,x15/14,s14,x9/11,s10,x14/13,s2,x15/7,pf/n,x0/3,s10,x4/9,s1,x10/14,s12,x13/7,pd/p,x11/14,s14,x4/13,pc/b,x11/2,s7,x1/4,pg/j,s3,x3/12,s11,pb/n,x6/15,s6,x4/7,pk/o,x2/1,s13,pe/n,x12/9,pp/o,x4/7,s15,x8/14,pn/b,x15/7,s14,x4/12,pm/e,x3/6,s7,x2/4,pk/f,x12/10,s2,x4/3,pl/e,x13/14,pb/k,x15/7,pn/d,x8/5,pi/f,x11/1,pa/g,x9/7,pi/j,s11,x4/6,s12,x5/1,s1,x2/8,pf/a,x11/13,s9,pg/e,s5,x2/1,s13,x8/9,s12,x13/2,po/h,x9/8,s8,x14/1,pc/k,x0/9,s7,pm/l,x10/12,s6,x8/7,s8,x5/2,po/p,x0/11,pa/k,x6/1,s10,x11/2,s5,x9/0,pc/p,x4/12,pg/a,x3/10,po/b,x7/2,s3,x12/5,s2,x9/0,pj/c,s6,pb/f,x10/7,s8,x4/11,pi/o,x13/15,s2,x4/14,s10,x7/1,s1,x8/13,pc/b,s6,pk/m,x9/3,pi/o,x6/14,pc/f,x8/2,ph/g,x13/9,pk/b,x3/5,pc/a,x1/6,s15,x7/11,s15,x8/5,s11,pp/j,x12/14,pi/e,x13/0,pd/f,x2/11,s8,x1/12,pi/k,x11/10,s15,x13/4,s1,x9/1,pg/d,x12/5,s2,x9/10,s9,x8/6,s8,x1/2,s10,pp/o,x8/3,s14,x0/11,pd/a,x4/2,s13,x8/9,s9,x1/12,s15,x6/0,pj/g,x7/3,s6,x12/9,s15,x1/14,s11,x0/7,pa/d,x9/10,s5,x8/3,s14,x0/15,s7,x5/8,pl/j,x4/1,s15,x6/0,pn/b,x2/11,pm/h,x15/6,s4,x11/13,s6,x3/7,po/g,x14/10,s1,x1/6,s4,x13/0,s5,x8/11,pj/c,x5/1,s2,x3/15,s2,x11/12,pi/g,x0/14,pd/l,x1/13,s6,x0/4,s13,x3/11,pf/e,x1/4,pg/c,x10/6,pn/e,x2/7,s8,x3/1,s8,x14/15,pb/g,x4/13,pc/d,x6/3,s15,x8/7,s12,x11/14,s13,x10/8,s4,x5/14,s8,x1/10,pm/i,x0/15,s7,x6/13,pk/h,x11/1,s9,x0/2,pe/a,x5/4,pm/b,x11/9,pi/p,x6/13,s12,pc/m,x14/12,pf/b,x3/4,pk/o,x9/8,s3,x7/2,s5,x11/8,s5,x1/7,s14,x15/13,s4,x10/6,s15,x14/15,s8,x11/0,pe/l,x15/10,po/k,x7/0,pb/g,x5/11,s5,x12/10,s5,x2/11,pa/i,x10/7,s15,x12/3,pm/g,x13/7,pl/e,x1/12,s12,x8/7,s14,x0/14,s8,x10/11,pj/m,x15/8,pf/p,x13/9,s14,x10/1,s11,x3/2,pb/d,x11/12,s10,x6/5,s7,x3/0,s11,x8/11,ph/a,x10/13,pc/d,s5,x11/3,s9,pi/n,x14/13,pg/o,s11,x7/6,ph/l,x13/15,s8,pk/f,x3/8,pp/e,x13/15,s2,x1/5,s4,x15/14,po/j,s9,x2/3,pn/e,s12,x4/14,po/c,x9/8,pg/e,x3/13,ph/l,s10,x4/15,s13,x1/9,pj/m,x4/11,pg/i,x8/14,s4,x15/3,pd/a,x10/8,pi/n,x2/5,pp/m,s8,x7/3,pb/e,x11/10,s6,x9/7,s4,x13/2,s11,x9/3,s3,x13/15,s7,x0/7,s2,x11/1,s1,pf/j,x5/10,pa/i,x1/11,s10,x6/7,s2,x2/4,s11,x9/14,pe/n,x5/1,s9,x13/3,s7,x14/4,s8,pl/o,x10/2,pm/f,x4/3,s3,x13/8,s10,x12/15,pj/h,s7,x9/2,s3,x11/10,s13,x5/3,s15,x8/13,s10,x10/0,pe/n,x7/8,s14,x2/14,s9,pb/f,x4/15,s6,x10/8,pe/p,x15/11,pd/l,x13/6,s8,x5/9,s9,x4/11,s10,x1/12,s1,x15/10,ph/i,x13/0,pd/l,x1/14,pa/p,x2/8,pn/f,x4/6,s3,x12/3,s7,x15/4,s7,x14/11,pd/j,x1/2,s1,x5/6,pi/a,x10/8,s7,x15/2,s1,x13/11,s8,pg/f,x12/2,s9,x10/5,s2,x2/13,pk/d,x9/3,pm/e,x12/5,s8,x2/14,s15,x5/13,s1,x8/4,s12,x15/11,pb/a,x14/10,s4,x12/4,s3,x11/3,ph/l,s9,x8/12,s4,x11/15,s5,x12/1,s2,x14/6,s14,x0/5,pf/m,x15/10,pi/c,x1/9,s4,pd/n,x3/5,s3,x14/6,s13,x8/3,pe/i,x12/6,s4,x13/1,s8,x8/3,pf/c,x1/9,s6,x7/12,s13,x11/8,pe/a,x9/0,s5,x8/14,s5,x9/7,s11,x3/0,s11,x8/14,s15,x5/1,s13,x4/6,pj/p,s13,x9/0,pf/i,x5/13,s14,x0/8,s11,x5/1,pc/a,x14/8,ph/l,x11/13,pf/c,x14/2,s2,x5/10,s12,x7/2,pg/m,x9/8,s9,x14/3,ph/k,x13/9,s1,x1/2,pj/p,s1,x14/5,s1,x0/1,pi/d,x4/3,s3,x10/13,s6,x14/11,pn/b,x3/10,pi/d,x2/1,s12,x10/4,s11,x14/2,ph/f,x5/1,s4,x14/3,s2,x15/13,s13,po/g,x11/12,s15,x14/10,s1,x15/11,s3,x6/2,s13,x15/7,s12,x5/14,s14,x3/2,pf/m,x13/12,pp/o,x9/4,s5,x1/13,s11,x3/7,s14,x5/9,pa/b,x7/0,s8,x6/15,pk/f,x9/1,pe/p,x10/15,s10,x7/9,s3,x6/2,pj/f,x11/4,s3,x6/10,po/m,s11,x14/9,s14,x15/0,s6,pf/g,x6/4,s3,x7/14,pe/m,x13/4,s3,x3/8,pa/d,x12/1,s14,x5/9,pn/c,x7/15,pi/o,x6/1,s6,x4/2,pa/c,x0/1,ph/n,x4/2,pf/k,x15/12,pj/b,x4/11,s1,x8/14,s5,pm/n,x0/6,pg/e,x1/8,pm/h,x10/6,pn/p,x13/11,s1,x0/15,s4,po/i,x11/5,pf/k,x13/7,s9,x1/10,s1,pm/n,x8/6,s2,x0/11,s14,x7/1,s13,x10/0,pc/p,x14/2,s1,x6/1,po/m,x0/5,s2,x4/6,s12,x7/10,s4,x14/2,s4,x11/7,s10,x8/9,pl/a,x7/4,pb/e,s9,x5/14,s6,x4/3,po/c,x13/5,s8,x3/15,s11,x14/0,pl/j,x1/4,pb/f,x7/8,s2,x11/5,pc/l,x0/12,pi/d,x13/9,pm/k,x2/3,s2,x15/14,pi/e,x2/9,s8,x8/12,s6,x4/3,pl/g,x10/14,s9,x9/3,s15,pm/d,x4/7,s7,x6/3,s4,x15/11,pl/b,x13/12,s2,x1/7,ph/e,x11/14,pc/i,x13/12,s13,x10/1,s9,x8/5,pe/k,x4/11,s5,x1/10,s13,x4/2,s15,x13/9,s9,pl/i,x5/10,s8,x7/3,pf/a,x1/2,s15,x3/8,pe/c,x9/12,pn/o,x3/14,s7,x4/9,pc/f,x10/8,s15,x12/6,ph/m,x14/15,s15,x8/2,pn/e,x11/7,s1,x6/14,pi/f,x1/15,s4,pl/h,x11/9,pc/k,x14/10,s13,x5/3,s5,x2/8,pi/g,x14/7,s2,x8/10,s8,x14/3,pm/l,s5,x15/1,pb/h,s6,x12/2,pc/l,x13/8,s8,x15/9,pf/b,x7/5,s11,x8/0,s13,x9/6,pj/c,x5/14,ph/m,x10/7,s3,x1/4,s10,x12/5,s13,x13/1,pi/o,x8/6,s15,x1/0,s6,x10/6,pc/a,x0/5,pb/j,x14/13,pp/c,x3/1,pa/b,x9/4,pi/p,x2/5,s13,x13/3,s5,x9/0,s10,x2/14,s1,x1/5,s4,x9/13,s3,x14/12,pc/d,x8/11,s12,x14/1,po/e,x11/4,s6,x15/12,pa/b,x13/1,pp/j,s12,x4/12,s6,x8/5,pg/n,x7/6,s9,x4/5,s10,x0/7,s3,x4/10,s13,x13/12,pd/p,x9/15,pn/m,x14/6,pj/p,x8/9,pg/k,x0/3,pf/h,x10/4,s1,x0/2,s3,x1/3,po/g,x6/12,ph/f,x4/8,s14,x12/11,s3,x9/7,s12,x13/15,pb/p,x6/2,pi/f,x8/12,s8,x0/7,pc/g,s6,x5/13,s13,x12/2,s4,x0/9,s11,x8/14,s14,x7/9,s6,x13/12,pk/n,x10/14,s1,x15/9,s14,x11/12,pf/m,x4/1,pi/d,x8/13,pg/e,s4,x5/11,s8,x15/0,s12,x1/12,pa/i,x14/7,pk/n,x10/11,s2,x6/13,ph/f,x5/2,s11,x15/7,pc/i,x2/13,s12,x8/0,po/l,s12,x6/15,s10,x3/1,s14,ph/i,s6,x10/0,s2,x2/9,s10,x5/0,pf/p,x6/3,pk/a,x1/4,pd/o,x0/2,pl/h,x12/8,s10,x7/6,s10,x15/10,pk/g,x7/9,s11,x11/13,pp/o,s7,x9/7,s2,x3/12,s8,x4/10,pj/l,x13/14,s12,x12/0,s11,x5/2,s11,x9/0,s1,x2/10,pg/i,x5/7,pc/m,x1/8,po/i,x13/0,s4,x3/6,s10,x4/9,s5,x8/10,s5,x11/6,pm/n,x10/2,pe/p,x14/8,s3,x1/11,s13,x9/3,ph/k,x0/15,po/m,x8/7,ph/c,x0/5,s13,x7/9,s9,x10/0,s6,x6/13,s8,x9/14,s10,x12/15,s10,x14/1,s11,x5/12,s12,pb/j,x7/15,s6,x12/13,pe/i,x10/6,s12,x5/15,s9,x14/2,s8,x0/11,s8,x1/5,pp/m,x6/9,pi/n,s5,x0/13,pa/o,x3/1,pi/e,x4/6,s6,x2/13,pp/f,x3/4,s5,x14/13,s5,x5/8,s7,x12/9,s6,x5/8,pn/c,x10/12,s14,x6/14,pb/d,x4/2,pn/o,x9/5,pk/a,x12/10,s12,x2/4,pf/p,x8/7,s3,x9/14,s13,x12/13,s7,x10/2,pa/m,x5/11,s15,x12/15,s5,x6/0,s3,x7/1,s9,x5/4,ph/f,x1/2,pa/n,x14/12,pm/c,x10/8,s12,x7/12,s11,x14/4,pp/a,x10/0,pe/f,x7/2,s5,x13/10,pl/i,x6/3,pp/e,x4/9,s10,x14/5,s15,ph/d,x9/3,s2,x7/4,pk/f,x5/15,s9,x1/2,s3,x10/9,pg/n,x0/15,s15,pm/e,x9/8,s3,x12/10,po/d,x4/13,pb/h,x15/3,s6,x14/10,s7,x15/11,s7,x0/3,pj/d,x2/10,s7,pf/l,s9,x12/5,s1,x15/2,s6,x6/0,ph/o,x1/10,s3,x12/2,pd/c,x10/15,pf/j,x3/14,s15,x11/12,pn/h,x0/13,s12,x2/5,s1,x12/1,pp/i,s13,x15/10,s13,x8/13,s15,x1/10,s1,x6/0,s14,x9/5,s11,x4/8,s11,x13/5,ph/d,x15/8,pp/i,s13,x13/10,pk/c,x2/14,s6,x3/11,s9,x9/5,pl/d,x4/12,pc/k,x10/5,pb/i,x8/4,pm/k,x1/2,s4,x8/7,po/f,x3/6,pb/d,x7/9,pn/i,s2,x6/3,pp/k,x9/0,s9,x15/2,s3,x13/10,s6,x9/14,s4,x1/7,s11,x11/6,s1,x1/8,s8,x14/10,pa/f,x5/15,pi/m,x4/7,pa/d,x8/5,pi/l,x9/13,s12,x4/12,s1,x15/8,s1,x7/9,s8,pc/g,x15/4,s11,x7/9,s13,x5/2,s14,x13/3,s8,x1/9,pj/k,x11/6,pc/e,x2/12,s6,x7/11,s14,x0/13,s2,x3/11,s11,x7/10,s12,x1/9,po/k,s11,x12/10,pm/d,x15/0,s11,x11/2,pp/n,x15/12,s3,x10/9,s13,x5/15,s4,x1/14,s9,x15/5,s15,x9/11,pf/c,x4/2,s13,x3/8,s15,x2/7,s10,x6/15,s4,x4/7,s4,x13/0,pm/e,x14/4,pn/f,x8/5,pb/k,s7,x6/15,s8,x10/11,s1,x3/0,s7,x15/1,s10,x8/0,s12,x10/3,pd/p,x1/8,s10,x15/10,s13,x13/8,pb/n,x3/2,s8,pl/c,x5/10,pn/a,s3,x0/8,s3,x12/13,s13,x7/3,s15,x0/8,pe/d,x15/4,s11,x12/14,s2,x10/6,s15,x8/13,s7,x3/10,s4,x6/11,s4,x3/13,s4,x12/15,s7,x7/10,s5,x1/5,s2,x8/14,s11,x9/0,s3,x2/15,s11,x8/10,s1,x11/6,s6,x4/9,s10,x5/7,pb/j,x6/12,s13,x5/8,pn/g,x10/6,pe/d,x0/8,pp/n,x1/14,s12,x7/3,pe/o,x11/1,pd/f,x3/12,ph/a,x10/7,s5,x0/6,s6,x1/9,s1,x3/0,s11,x14/7,pb/i,x4/1,pd/n,x9/0,s4,pj/b,x10/15,s6,x5/14,s14,x6/11,s3,pc/p,x4/2,s3,x1/6,ph/j,x12/5,s8,x2/8,s9,x5/9,s6,pa/i,x6/10,pg/j,s3,x7/12,s11,x3/4,s8,x6/10,s8,x3/9,pm/l,x12/2,s14,x13/6,pp/k,s6,x9/14,s4,x7/5,pj/d,x8/14,s6,pp/b,x2/1,pi/o,x7/5,pp/k,x9/14,s9,x12/1,pl/d,x10/2,s3,pn/e,s11,x9/12,pm/d,x7/15,pc/i,x6/2,s5,x15/14,s9,x13/2,s14,x5/7,s10,x1/6,pf/a,x11/0,s11,x4/7,s12,x5/12,pe/o,x6/3,pp/a,x7/12,pb/l,x0/9,s6,x13/3,pm/c,x9/1,s14,x10/5,s7,x12/6,ph/i,x14/1,s15,po/b,x15/4,s5,x14/0,s8,x6/15,s3,x7/4,ph/m,s10,x9/5,s13,x6/3,pl/p,x10/14,s7,pa/e,x1/3,s14,x14/9,s11,x5/13,pk/h,s11,x9/14,s1,x3/4,s13,x9/11,pa/p,s5,x8/0,s3,x7/11,ph/n,x14/1,pp/j,x5/4,pk/f,x3/0,s5,pd/p,s12,x1/9,s12,x5/0,s14,x10/15,s15,x5/4,po/h,s2,x9/8,pa/g,x1/4,s1,x11/10,s4,x2/13,s7,pb/n,x14/7,pc/p,x3/0,s2,x12/2,s2,x7/15,pm/n,x10/8,pj/h,x0/14,s12,x13/3,pa/o,x11/8,s10,x5/13,ph/i,x1/12,s9,x13/8,s14,x14/11,s2,x6/13,s1,x1/8,s11,x14/4,s5,po/n,x2/12,pk/g,x10/14,s11,pi/j,x1/2,pd/h,x5/15,s2,x13/9,s1,x1/15,s3,x8/3,pn/g,x5/4,pa/f,x8/12,s9,x7/0,s14,x11/10,s14,x13/5,s5,pc/g,x11/6,s1,x15/8,po/e,x2/7,s1,x0/5,pd/c,x10/1,pi/b,x9/4,s11,x7/12,s14,x1/8,s4,x15/5,s3,x11/8,s6,x5/1,pg/p,x10/4,s5,pa/n,x3/11,pp/e,x1/4,s13,x12/6,po/a,s8,pk/i,x13/9,s2,x3/0,s9,x14/9,s13,x0/13,pl/d,x9/12,pb/i,x10/6,s4,x5/7,s3,x13/14,s9,x3/6,s6,x14/11,pj/g,x13/7,s2,x9/3,pn/b,x14/5,s8,x0/8,s1,x9/6,s7,x5/14,s14,x11/0,pi/h,x13/7,s15,x8/6,s10,x9/2,pp/g,x12/1,s11,x2/9,s7,x10/7,s9,x11/2,pe/h,x9/6,pc/m,x14/1,pe/o,x10/3,s14,x4/1,s15,x7/13,pb/g,x12/15,pd/l,s9,x11/0,po/i,x3/7,s1,x9/4,pp/m,x13/2,s12,x7/4,po/b,x15/12,pc/k,s10,po/e,s1,pd/g,s14,pc/e,x0/7,pm/p,s3,x10/13,s9,x12/14,s15,x6/0,s13,x9/3,pc/f,x10/14,s4,x2/11,pl/o,x12/14,pi/a,x10/4,s12,x0/13,pl/p,x14/3,pe/b,s14,x12/0,s4,pk/p,x4/10,s15,x11/14,s2,x7/6,s1,pl/n,x9/15,s12,x1/4,s6,pg/h,x9/14,s2,x10/15,pl/e,x13/14,pi/f,x6/1,pd/l,x13/5,s8,x7/8,s8,x14/9,po/a,x1/15,s14,x6/5,pl/d,s1,x12/10,s2,x14/15,pj/a,x13/7,s15
-    "#;
-    println!("answer: {}", run("abcdefghijklmnop", &input));
+fn parse_dance(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input.split(',')
+         .map(|s| s.trim())
+         .filter(|s| !s.is_empty())
+         .map(|s| parse_instruction(input, s))
+         .collect()
 }
 
-fn run(line: &str, input: &str) -> String {
-    let mut dancers = line.split("").filter(|s| !s.is_empty()).collect::<Vec<_>>();
-    let instructions = input.split(",").filter(|s| !s.is_empty()).collect::<Vec<_>>();
-    let mut cache: HashMap<String, Vec<&str>> = HashMap::new();
-    for i in 0..1_000_000_000 {
-        let orig = dancers.join("");
-        if cache.contains_key(&orig) {
-            dancers = cache.get(&orig).cloned().unwrap();
-        } else {
-            for instr in &instructions {
-                match parse_instruction(instr.trim()) {
-                    Instruction::Spin(s) => {
-                        let num = dancers.len() - s;
-                        dancers.rotate(num);
-                    }
-                    Instruction::Exchange(l, r) => {
-                        let a = dancers[l];
-                        let b = dancers[r];
-                        dancers[l] = b;
-                        dancers[r] = a;
-                    },
-                    Instruction::Partner(l, r) => {
-                        let a = dancers.iter().position(|s| s == &l).expect("Couldn't find dancer");
-                        let b = dancers.iter().position(|s| s == &r).expect("Couldn't find dancer");
-                        let l = dancers[a];
-                        let r = dancers[b];
-                        dancers[a] = r;
-                        dancers[b] = l;
-                    },
-                };
-            }
-            cache.insert(orig, dancers.clone());
+fn dance(dancers: &mut [char], instructions: &[Instruction]) {
+    for instr in instructions {
+        match *instr {
+            Instruction::Spin(s) => dancers.rotate_right(s),
+            Instruction::Exchange(l, r) => dancers.swap(l, r),
+            Instruction::Partner(l, r) => {
+                let a = dancers.iter().position(|&c| c == l).expect("Couldn't find dancer");
+                let b = dancers.iter().position(|&c| c == r).expect("Couldn't find dancer");
+                dancers.swap(a, b);
+            },
         }
     }
-    dancers.join("")
+}
+
+// the line comes back round to somewhere it's been before long before a billion dances, so only
+// dance until it does
+fn run(line: &str, instructions: &[Instruction], times: usize) -> String {
+    let mut dancers = line.chars().collect::<Vec<_>>();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut order: Vec<String> = vec![];
+    for i in 0..times {
+        let orig = dancers.iter().collect::<String>();
+        if let Some(&start) = seen.get(&orig) {
+            return order[start + (times - start) % (i - start)].clone();
+        }
+        seen.insert(orig.clone(), i);
+        order.push(orig);
+        dance(&mut dancers, instructions);
+    }
+    dancers.into_iter().collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Instruction>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+        Ok(parse_dance(input)?)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<String, Error> {
+        Ok(run(DANCERS, instructions, 1))
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<String, Error> {
+        Ok(run(DANCERS, instructions, 1_000_000_000))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    // #[test]
-    // fn it_works() {
-    //     assert_eq!(&run("abcde", "s1,x3/4,pe/b"), "baedc");
-    // }
+    #[test]
+    fn it_works() {
+        let moves = Day::parse("s1,x3/4,pe/b\n").unwrap();
+        assert_eq!(&run("abcde", &moves, 1), "baedc");
+        assert_eq!(&run("abcde", &moves, 2), "ceadb");
+    }
 
     #[test]
-    fn answer() {
-        let input = r#"
-            pn/m,x10/11,s12,x15/8,pl/o,x4/9,pn/h,x10/1,s15,x8/2,s15,x4/15,s12,x3/1,pa/k,s13,x0/2,s11,pb/m,x11/6,s5,x3/15,s10,x7/10,pn/l,x8/2,s13,x7/13,s15,x3/9,pj/p,x14/13,s14,x10/15,s4,x4/9,s6,x11/14,pc/i,x10/3,s15,x1/12,s15,x9/10,pg/p,x2/14,s2,ph/e,x1/5,s6,x6/14,pb/d,x2/13,pp/l,s5,x4/6,pk/o,x13/3,s6,x7/10,s6,x11/1,pm/h,s1,x0/15,s5,x11/8,s11,x9/4,pd/n,x8/11,pb/i,s8,pa/f,s13,x4/6,pe/o,x10/1,s8,x12/5,s13,x3/6,s11,pd/b,x5/9,pn/m,x0/6,pa/l,s11,pj/f,x3/12,s3,x15/13,s4,x3/9,ph/o,x14/0,pe/b,x3/9,pd/k,x6/14,pe/i,s9,x11/0,pp/c,x1/12,pg/d,x13/4,s14,x2/6,s3,x7/15,s12,x13/14,pm/h,s8,x2/8,pb/j,s10,x9/4,ph/p,s6,x7/15,s3,x14/9,pc/g,x11/0,s11,x5/7,s5,x9/6,s13,x4/15,pf/h,s14,x13/14,s10,x6/2,s12,x13/0,s13,x1/9,s2,pc/m,x14/6,s4,x12/5,pb/a,x11/13,pe/j,x6/4,s11,x15/13,pk/o,x5/0,s11,x9/11,s9,pj/i,x14/7,s6,x3/12,s7,x5/14,s1,x10/12,pl/p,x5/13,pb/c,x8/10,pd/g,x15/0,s10,x12/13,pf/e,x0/1,s8,x3/11,s10,x2/12,s7,x5/13,pl/m,s13,x4/1,s11,x0/15,pn/h,x9/4,s4,x1/14,pa/b,x10/8,s9,x13/5,s5,x4/2,pi/f,s2,x6/10,pp/k,x7/0,pg/n,x13/15,pd/i,x12/2,pj/n,x4/7,s5,x3/12,s15,x14/5,s14,x13/1,s7,x7/0,pp/o,x2/5,s14,x9/13,s7,x5/11,s2,x12/6,s12,x13/5,s5,x0/7,s11,x15/10,s14,x13/3,s7,pm/d,x9/5,s15,x3/11,s9,x12/7,pf/c,x4/15,s2,x12/6,s9,x4/13,s7,x14/1,pb/g,x9/3,pk/m,x6/0,s14,x10/9,pd/b,x0/6,pf/p,x15/1,pe/o,x9/5,pg/p,s11,x7/6,s11,x10/1,s14,x14/6,s12,x1/15,s2,x6/5,pj/a,x4/0,pb/h,x12/7,pk/e,s13,x9/10,s1,x8/15,pj/c,x10/1,s7,x6/14,s8,x13/9,s1,x7/8,pi/n,x0/11,pb/a,x2/8,s5,x7/10,s11,pp/g,s9,x12/5,s2,x11/1,pj/a,s7,x8/2,s13,x0/14,s5,x12/6,s6,x14/13,s8,x12/9,pk/p,x8/6,s3,x3/5,pc/f,x6/13,pb/m,x12/9,s13,x8/7,s11,x4/6,s7,x15/1,s8,pp/e,s11,x6/14,s1,x15/2,s3,x5/1,pd/h,x10/12,s4,pk/n,x6/14,s8,x8/12,po/m,x11/14,s2,x6/0,s6,x4/8,s11,x1/9,pj/g,x2/4,s10,pk/f,x15/0,s8,x13/1,ph/e,x8/10,s7,x6/2,pc/d,x1/7,s15,x12/6,s9,pg/p,x8/14,s11,x2/15,s11,x13/11,s1,x15/4,s5,x13/3,pb/h,x5/6,pm/f,x14/2,s13,x11/1,s5,x9/10,pi/n,x3/6,s3,x1/4,pm/k,s6,x10/11,s14,x15/1,ph/c,x13/5,pp/i,x4/3,s3,x6/11,s5,x3/8,s2,x0/7,s5,x3/6,s6,x14/5,pc/d,x12/13,s8,x5/9,s8,x12/13,s9,x8/3,s14,x15/13,pf/j,x4/3,pk/a,x10/1,s15,x13/14,s14,pd/c,x10/15,po/h,s13,x5/1,s8,x12/10,pm/g,s3,x11/1,pp/h,x10/6,s9,x2/5,s1,x13/6,s9,x9/3,pa/m,s3,x6/13,s8,x7/11,pd/p,x0/8,pf/j,s11,ph/b,x1/2,s2,x12/10,pm/c,x6/9,s3,x3/15,s15,x11/6,po/j,x9/7,pp/l,x8/6,pg/i,x2/4,s11,x12/14,ph/c,s9,pg/e,x4/15,pm/f,x5/13,pp/j,x6/3,po/n,x1/11,pl/b,x15/3,s10,x14/13,pe/n,x15/10,s7,x6/0,s11,x1/11,s3,x6/3,s11,x4/12,pg/d,x8/1,s6,x5/9,s10,x12/7,s4,x10/9,s6,x4/2,s14,x12/8,s3,x10/11,pa/b,x4/8,pi/l,s12,pg/f,s13,x3/2,pe/p,x0/14,s5,x12/1,s8,x9/3,pg/h,s8,x8/13,pc/b,s11,pp/d,x12/10,pc/f,s3,x1/11,s9,x12/4,s14,x2/14,s8,pg/p,x1/9,s10,x11/7,s7,x0/12,s4,x2/1,s5,x0/11,s7,x3/10,s7,x0/15,s6,x5/11,s14,x4/8,s13,x6/12,pa/i,x8/4,pd/j,x2/0,s3,x12/13,s14,x4/15,s12,x7/13,s8,x10/14,pa/k,s9,x1/0,s9,x2/13,s13,x6/3,pe/g,x13/10,pk/j,x0/15,s1,x2/10,s11,x4/12,s2,x3/8,s12,x9/4,pe/c,x15/7,s8,x0/1,s8,x15/6,pm/a,x3/5,pe/h,x7/1,s2,x10/5,s11,x7/6,s3,x4/14,pk/d,s2,x9/15,pa/f,x1/7,s10,x5/15,pd/b,x9/14,s8,x7/1,pa/k,x8/11,pj/l,x5/10,pd/g,x8/7,s1,x9/0,pi/e,x15/2,s9,x7/1,s14,x3/15,s15,pj/m,x6/12,ph/i,x13/7,pj/c,x14/0,s14,x11/1,pk/l,x0/9,pg/d,x1/2,pb/n,x9/15,pi/c,x4/6,pe/m,x13/12,s1,x2/15,s13,x13/3,s12,x7/11,s14,x10/13,pl/j,x3/7,pf/d,x14/0,s9,pa/e,x8/5,pg/n,x14/12,s7,x3/7,s3,x8/12,pe/l,x0/13,po/h,x15/5,s12,x12/14,s4,x0/11,s14,x2/13,pj/a,x14/15,s12,pg/n,x11/5,pj/d,x7/4,s1,x2/13,pg/n,s13,x3/12,s5,x0/4,pm/e,x5/15,s3,x1/3,s2,pl/k,x13/10,s15,x5/14,pm/c,x9/15,s9,x0/13,s9,x12/14,s14,pl/k,x11/4,ph/f,x7/6,pl/g,x0/12,pi/e,x3/7,pa/p,s12,x2/12,s5,x1/5,po/g,s11,x0/3,pj/p,x4/12,s11,x14/0,ph/g,x11/5,s11,x8/9,pj/f,s9,x4/2,po/i,x11/13,s2,x2/6,pm/l,x15/12,po/k,s7,x5/4,s6,x0/1,pb/p,x7/9,s2,x1/6,pl/k,x0/13,s8,x1/12,s9,x10/15,pb/o,x1/2,s5,x13/14,s15,x11/2,s11,x0/6,s3,x3/11,s3,x1/9,s10,x3/8,ph/i,s10,x13/15,s6,x7/6,s5,x10/8,s14,x0/2,po/g,x11/3,pk/i,x4/12,s15,x11/6,pf/e,x4/5,s15,x15/9,s15,x5/7,s9,x1/4,s7,x9/12,s10,x8/4,pm/a,x11/2,s9,pp/c,x3/0,s7,x10/12,pn/g,x1/3,s9,x10/11,s11,x1/13,s4,pb/k,x15/5,po/n,x9/7,s13,x6/0,pi/e,x1/12,s10,x14/0,s2,x15/7,s6,x11/4,pm/h,x14/8,pd/o,x4/15,pa/j,x2/7,s2,x13/4,s3,x10/9,pb/k,x5/1,s8,x13/11,s12,x4/14,pe/n,x11/12,pk/m,x13/5,s1,x9/12,s13,x8/13,pg/e,x10/3,s12,x11/14,pm/d,x7/10,s10,x8/4,pp/h,x7/6,s13,x0/8,s4,x13/14,s2,x4/11,pa/m,x6/0,pn/h,x15/8,s2,x9/3,po/g,x8/7,s7,x9/1,s12,x14/13,s10,x9/5,s5,x2/11,s8,x7/1,s4,x3/12,pk/l,x0/15,pc/h,x12/11,pg/l,s12,x10/5,s1,x14/12,s6,x0/15,s11,x10/5,s10,x7/6,s7,x2/1,s7,x12/9,pn/k,x14/8,s8,x7/3,pa/g,x6/12,s8,pk/j,x11/0,s5,x8/15,s4,x1/2,s5,x3/7,pf/l,x4/12,s10,x9/5,s12,x8/12,s13,x4/5,s2,pk/j,x3/12,pp/c,s15,x2/6,s13,x13/9,s5,x2/10,pk/d,x13/6,s7,x8/4,po/c,s3,x0/2,s14,x9/12,pk/f,x7/4,s5,x13/1,s10,x12/11,pc/o,x6/15,pj/g,x2/4,pe/a,s8,x15/8,pj/c,x5/11,pb/f,x12/3,pd/p,x1/14,s7,x9/8,s11,x13/12,pa/m,x11/1,s11,x13/6,po/i,x3/5,s13,x4/2,s12,x15/3,pe/d,x13/0,s1,x1/11,pa/f,x5/0,s15,x2/9,s1,x5/14,s9,x1/7,s14,x0/14,s4,x10/1,s13,x12/15,pm/e,x1/6,s5,x8/10,s13,pj/k,x2/0,pc/n,s14,x7/15,s2,x5/11,pa/m,x9/7,pj/o,x5/12,s13,x13/10,pd/l,s9,x5/3,s3,x10/8,pi/m,x2/13,s7,x8/14,s14,x4/5,s4,x12/13,s12,x15/14,s9,x8/11,pc/p,x0/4,ph/n,x9/12,pg/k,x5/10,pc/m,x3/1,s11,x9/12,pl/k,x0/8,s11,x13/6,pi/f,x1/15,pg/c,x0/12,pp/d,x13/4,pk/c,x15/2,s13,x12/8,s8,x6/7,s7,x5/11,s15,x9/8,s1,x15/3,s12,x2/7,s9,x15/1,s5,x12/7,s6,x11/0,s6,x3/1,pa/i,x0/6,s8,x7/11,s9,x10/13,s11,x2/0,pp/g,x8/13,s12,x7/11,s13,x6/15,s1,x8/7,pl/j,x3/2,s4,po/k,x9/13,pa/n,x3/2,pm/e,x8/5,pg/k,x12/14,s13,x3/4,pl/p,s10,x15/13,s3,x4/10,pe/n,s4,x0/3,s13,x15/8,s10,x9/3,s4,x8/5,s5,x1/14,s12,pf/o,x12/2,s14,x6/11,s10,x1/9,s2,pd/l,x5/10,s11,x9/0,s11,x5/11,s4,x2/7,s6,x0/13,s7,x12/3,s3,x9/13,s15,pa/h,x12/0,s6,x6/1,s11,x8/14,s11,x9/7,s6,x5/13,s1,x7/11,po/c,x10/0,pd/n,x9/14,s13,x12/2,pc/i,x13/6,s12,x12/5,pk/m,x6/14,s5,x3/0,s4,x11/9,pf/h,x2/4,pk/c,x0/13,pj/m,x9/3,po/c,x1/10,pl/a,s2,x14/2,pj/b,x13/1,pk/n,x9/15,pc/p,x8/5,s2,x10/15,s1,x2/14,s14,x9/13,s6,x0/1,s14,x9/2,s15,x14/8,s1,x4/9,s7,x6/13,pa/h,x5/8,s12,x4/10,s9,x11/3,s1,x9/8,pm/j,x15/7,s11,x12/5,s2,x2/0,s13,pd/o,s3,x14/3,pp/n,x4/0,pe/g,s14,pk/m,x9/11,pj/l,s3,x4/2,pd/e,x6/0,s6,x15/1,pc/g,x14/3,s15,x10/7,s10,x12/14,s10,x13/5,s10,x15/9,s11,x11/7,pi/b,x10/15,s2,x13/14,s5,x7/6,s15,x11/9,s7,x14/8,pg/a,x4/0,s7,x15/11,s7,x2/3,pf/c,x1/5,pm/l,x10/11,s6,x0/8,pn/k,s3,x2/13,s11,x12/15,s7,x11/6,s10,x0/2,ph/p,x3/1,s2,x15/10,s15,x5/6,s12,x11/4,s9,x5/13,pj/c,s12,x11/6,s1,x14/3,s4,x6/15,s2,x5/0,s8,x13/10,s13,x12/11,s5,x7/8,s7,x15/14,pe/l,x8/12,pf/i,x6/1,s6,x7/13,pj/a,x6/3,s12,x1/10,s14,x12/0,pn/p,x9/15,s8,x11/10,pm/l,x7/12,s11,x6/15,po/k,x10/2,s2,pa/e,x8/14,s15,ph/o,s3,x12/13,s6,x1/2,s9,pl/j,s5,pk/c,x9/0,pn/a,x12/7,s2,x8/0,pc/f,x14/2,s9,x12/6,pp/j,x7/15,s6,x0/13,s2,x2/15,s8,x5/1,pe/l,s1,x7/4,pg/d,x6/8,ph/c,s6,x15/11,s5,x14/6,pi/g,s7,x10/11,s13,x14/1,s13,x4/12,s6,x15/6,s15,pb/k,s13,x1/14,s13,x5/15,pl/p,s10,x6/11,s12,x5/2,pn/o,x4/14,s2,x11/9,pi/j,x5/1,s11,x11/6,s4,pe/m,x10/8,pl/d,s5,x15/6,pe/h,x3/0,s14,x11/13,pj/d,s1,pi/n,x12/9,s4,x10/4,pf/h,x14/3,pb/m,x8/13,s6,x1/0,s6,x4/7,s13,x9/6,s1,x12/2,s9,x5/7,s2,x15/14,s10,x1/12,s12,pf/k,x6/13,s3,x9/14,pd/j,x4/3,s7,x12/6,s11,x10/1,s13,x14/2,s10,x6/4,pg/e,x14/3,s4,x2/4,s12,x11/9,pj/o,s11,x10/1,pa/g,x13/0,pj/n,x7/4,s6,x9/1,s8,x11/4,pe/h,x1/9,s10,x4/8,pn/l,x11/1,s3,x0/3,s14,x4/14,pi/p,x0/1,s13,x15/10,s12,x5/8,s15,x13/7,pn/g,x4/14,s12,x6/9,s4,x3/13,s10,x7/9,s1,x11/3,s1,x14/12,pm/c,s4,x2/1,s2,pk/a,x6/0,s5,x8/13,pi/b,x12/7,pe/c,x10/13,s4,pk/a,s15,x7/2,s1,x5/0,s14,x3/8,s1,x13/11,s10,ph/b,s10,x6/14,s12,x8/10,s3,pm/j,x1/12,po/i,x8/6,s7,x11/13,s14,x5/4,pg/n,x7/10,s13,x5/11,pi/j,x1/2,pp/k,x10/3,pd/g,s15,x0/5,pa/o,x4/6,pi/e,x8/1,s14,x4/6,s10,x13/2,s9,x12/3,s8,x10/2,pn/b,x14/8,s1,x13/9,pf/k,x7/3,pc/n,s1,x13/10,pk/h,x11/0,pa/e,x12/3,s11,x1/4,s6,po/m,x5/6,pl/p,s2,x3/14,s10,x11/0,s8,x4/13,s2,x6/14,s1,x3/7,s10,x10/9,s2,x7/11,s9,x8/5,s15,x3/14,s14,x4/9,s2,x3/5,pm/o,s1,x12/11,s11,x6/5,pk/a,x10/15,s8,pj/d,x1/0,s3,x10/6,s2,x3/12,pc/f,x0/14,s14,x7/10,pd/o,x14/11,pn/b,x7/10,s8,x2/15,pe/a,x12/7,pi/d,s2,x0/6,pg/m,x11/13,pe/c,x3/9,ph/k,x2/1,pn/a,x9/8,s12,x2/10,s4,x13/1,pl/c,x2/9,pn/k,x12/13,s4,x11/15,s8,pm/i,x10/6,pa/d,x1/11,s3,x7/15,s4,x4/1,s3,x13/5,pj/k,x4/14,pl/m,x13/1,s8,x8/6,s4,x1/5,s15,x0/12,s7,x3/13,s7,x2/11,pc/i,x6/4,pk/j,x5/0,pb/i,x2/15,pj/e,x4/14,s11,x2/6,s15,x8/4,s3,x13/2,pf/d,x8/1,po/k,s9,x11/13,pl/j,s15,x12/1,pc/m,x11/14,s15,x15/5,ph/d,x2/3,s8,x11/6,pk/i,x3/4,s10,x15/2,pa/l,x5/10,po/b,s14,x3/12,pj/c,x11/9,pi/l,x10/15,s1,x9/13,s8,x7/15,pd/c,x4/6,s6,x11/7,s2,x5/3,pb/j,x13/10,s9,x0/11,pc/d,x7/13,pk/e,x4/1,s10,x10/15,s3,x9/0,pn/g,x10/4,s1,x13/5,pe/j,x0/12,s2,x6/5,pm/f,x14/0,s7,x10/8,pg/d,x12/5,s2,x1/8,pc/n,x6/11,s11,x3/15,s5,x14/1,po/e,x11/0,s14,x4/13,s13,pm/j,x8/5,pd/n,x4/9,s9,x12/6,s15,x1/5,s2,x4/14,s13,x6/1,s12,x9/15,s14,x4/12,pk/o,s6,x1/2,pm/i,s1,pf/b,s12,x14/10,s15,pa/c,s12,x12/11,s15,x5/0,pg/b,x12/13,s5,x5/8,s11,x2/0,s4,x11/4,s9,x0/12,s13,x15/8,pk/i,x4/7,pm/a,x0/10,s6,x11/14,s14,x9/5,pi/n,x11/1,s14,x13/8,pk/g,x5/6,s7,x14/9,s6,x7/2,s6,x11/0,pl/b,x4/15,pp/e,x0/13,s5,x12/5,s13,x4/13,pc/a,x15/7,s10,x11/9,pe/h,x0/8,s15,x9/10,pi/b,x3/4,s1,x1/2,pg/e,x10/13,s14,x6/12,s2,x8/9,pd/m,x14/15,pi/l,x7/9,pn/f,x11/6,pm/c,x10/9,s15,x5/15,pb/e,x13/12,pd/c,x4/1,s7,x3/13,s12,x4/0,s3,x8/1,pk/p,x12/6,pe/n,x1/11,pl/c,x15/12,po/d,s2,x11/14,s10,x12/13,pm/e,x0/2,s1,x11/15,s7,x7/8,s10,x3/4,s10,pp/f,x2/10,pd/o,x0/4,s3,x5/7,pa/g,x2/4,pi/p,x8/0,s14,x3/12,s11,x10/13,s11,x7/3,pd/g,x5/13,pp/c,x8/15,s2,x6/3,s15,x1/5,pd/a,x11/10,pn/o,x8/6,s9,x2/12,s10,x10/6,pd/m,x9/4,pe/l,x1/3,pn/o,x15/2,s14,x8/13,s12,x3/11,s1,x2/4,s6,pm/d,s15,x11/6,s10,x7/1,s10,x0/6,po/k,x2/15,s3,x13/12,s10,x0/6,pj/e,x12/9,s4,x10/4,s12,x5/14,s1,x9/1,pk/h,x0/3,pb/c,x15/5,po/g,x9/8,pf/c,x3/11,pg/d,x6/10,s5,x1/9,pa/k,s1,x14/13,s15,x2/8,s6,x0/12,pc/f,x11/5,s1,x14/4,s14,x10/0,s3,x7/14,s11,x9/3,pi/m,s13,x10/14,pk/e,x13/11,s12,x3/10,s4,x4/12,pn/m,x11/13,pp/j,x4/9,s7,x8/10,pe/b,s5,x9/0,s14,x8/6,s15,x2/15,pa/c,x8/9,pg/i,x14/3,pp/k,x9/7,s2,x12/10,s14,x7/11,s7,x10/0,s10,x1/3,pb/f,x5/12,s15,x2/14,pp/l,x5/11,s3,x1/4,s2,x3/7,s14,pi/m,x14/4,s6,po/l,x15/7,s7,x5/8,s9,x15/10,s2,x0/6,ph/j,x9/1,s11,x8/13,s2,x9/10,s1,x14/5,s11,pp/c,x0/6,pn/h,x2/13,s1,x3/8,pj/o,x6/5,s11,x4/15,s7,x7/6,s11,x4/12,pa/p,x5/10,po/d,x7/3,pn/k,x6/11,s10,x15/7,pf/a,x5/3,s5,x6/8,pi/n,x12/7,po/p,x1/2,s12,x10/8,s12,x5/11,pk/g,x14/4,pf/h,x10/15,po/a,x8/7,s12,x15/14,pp/b,x12/7,pd/e,x5/14,s15,x6/8,s3,x4/1,pf/o,s1,pc/k,x14/5,pd/i,s11,x6/2,pc/b,x8/13,s8,x2/5,s4,x3/4,s11,x6/11,s3,pf/i,x1/0,pc/h,x7/4,pd/f,x6/13,pp/e,x10/2,pd/a,x9/7,s10,x15/5,s3,x14/2,pf/p,x0/9,s5,x12/11,s14,x15/7,s8,x5/1,pc/m,s7,x4/2,po/i,s11,x10/0,s6,x4/3,s12,ph/a,s12,x10/0,s6,x4/6,s13,x9/13,s4,pg/e,x3/4,s6,x7/2,s8,x9/5,s3,pb/a,s7,po/e,x3/13,s5,x0/5,pf/g,x9/2,s10,x0/12,ph/p,x7/3,s5,x2/8,pi/c,x10/11,s10,x6/3,po/a,x11/0,pc/d,x9/3,s4,x6/2,s10,x12/10,s4,x11/1,s15,pb/k,x0/6,s15,pp/e,s14,x13/3,s10,x7/12,s11,x14/5,pn/g,s4,x1/13,pa/e,x10/15,pp/c,x6/4,s11,x1/15,s15,x0/4,s5,x5/3,ph/m,x10/2,pn/g,x13/15,s15,x6/4,pa/i,x3/1,pd/h,x15/5,pf/l,x2/7,ph/e,x13/0,pj/k,x11/1,s1,x3/10,s9,pf/g,x15/12,s5,x7/13,pl/a,x11/3,pe/c,x0/4,s12,x10/5,s13,x6/1,ph/k,x9/7,pn/a,x2/8,s2,x15/1,s14,x2/4,s5,x3/15,s5,x11/2,s5,x10/6,s6,x13/7,s7,x0/14,s11,x4/2,s8,x6/11,s3,x3/7,pm/l,x11/15,pc/j,x4/5,pp/b,x9/10,pn/h,s2,x11/14,s7,x2/7,pl/m,x13/6,s2,x11/3,pn/g,x4/10,s15,pk/d,x11/13,pe/n,x0/12,po/m,s1,x4/13,s10,x14/3,s4,x8/1,pg/k,s6,x12/5,pd/a,x3/13,pn/g,s15,x11/15,s8,x5/14,s7,x12/2,s14,x4/8,pm/c,x9/10,s7,x11/2,s3,x12/14,ph/d,x5/7,pk/o,x9/2,pj/c,x7/8,pb/h,x3/5,s7,x14/15,pn/l,s10,x7/0,s5,x12/3,pj/g,x14/2,s1,x5/9,s1,pb/k,s12,x12/6,s13,ph/g,x3/4,s2,x15/5,pn/a,x12/9,s4,pc/d,x10/11,pn/m,x1/5,pa/b,s8,x10/8,pc/p,x1/4,pd/m,s8,x0/5,pc/k,x1/10,ph/g,x4/8,s3,x6/1,s3,x2/3,pc/f,x4/10,pb/l,x1/7,s1,x13/6,s7,x14/4,s15,x13/5,s4,x1/6,pp/h,x8/5,pa/e,s5,x7/1,pp/j,x6/14,s15,x0/15,pl/h,x6/12,s4,x13/15,s3,x5/14,pm/n,x6/12,s1,x14/0,s2,x13/10,pj/o,x9/7,s12,x3/4,s14,x11/6,s13,x3/0,s3,x13/4,s1,x11/15,s5,x3/10,pi/a,x12/4,pd/n,s15,x8/0,s6,x6/11,s4,x4/8,pp/a,x13/0,s15,x14/9,pm/e,x3/11,s15,pi/c,s8,x6/13,pm/e,s9,x11/3,s7,x4/2,s5,x14/10,pj/h,s12,pa/k,x15/9,s3,x3/13,s11,x4/11,pj/i,x6/7,s2,x11/8,pm/e,x5/9,ph/n,x15/12,pi/p,x1/10,s10,x0/15,pk/d,x14/5,s8,x7/6,pa/p,x3/4,pl/i,x6/5,s13,x11/15,s7,x2/9,s12,x8/11,s11,x9/4,s14,x11/10,s4,x12/14,s10,x0/5,pm/o,x1/3,s1,pa/i,x11/6,s11,pm/g,x13/5,pi/l,x0/15,pk/h,x8/4,pj/l,x6/10,pp/f,x3/8,s4,x0/6,pa/m,x7/15,s1,x5/13,po/b,x12/6,ph/c,s7,x4/13,pf/j,x10/12,s9,x7/15,pa/d,x1/9,s14,x15/12,s9,x1/9,s1,x0/4,pn/h,x3/1,s15,x5/4,pm/o,s10,x7/0,s3,x6/8,pk/h,x7/13,po/b,x12/3,s14,x2/11,pi/a,s7,x3/8,pc/o,x9/12,s7,x11/1,pi/n,x3/15,s4,x1/2,s2,x7/5,s6,x12/10,pa/h,x7/11,s10,x8/1,pm/j,x12/2,pi/p,x0/14,s13,pl/j,x12/1,pc/m,x11/13,s13,x1/10,s12,x6/15,s10,x4/5,s14,x7/9,s12,x5/12,s5,x1/10,pb/f,x15/7,pk/g,x9/13,pe/c,s12,x5/4,s7,x15/6,s10,x0/13,pd/n,x7/12,s12,x15/2,s15,x9/10,s15,x0/11,s15,x7/1,pg/p,x13/0,pl/f,s10,x12/11,s7,pp/m,x5/1,pn/f,x3/14,pk/a,x12/13,s7,pi/g,s11,x4/6,s15,x3/0,pk/c,x12/6,s3,x5/0,pg/o,x9/4,s15,x13/2,pk/l,x12/0,po/p,x11/13,s10,pl/m,x10/4,s2,pj/d,x6/3,s9,x8/10,s12,x1/4,s7,x8/14,s2,x7/3,pa/o,x1/11,s15,x10/7,s9,x13/6,s6,x10/5,s15,x1/3,s15,x12/15,pi/b,x3/0,s11,x14/9,s10,pj/o,x10/1,pa/g,x11/13,s12,x8/15,s7,x7/1,pl/c,x3/14,pk/p,x8/6,pe/h,x7/14,pa/j,x8/12,s6,x7/5,s6,x4/13,pp/c,x3/0,pg/b,x15/2,pn/j,x10/8,s11,x6/0,s7,x8/14,po/a,x2/10,pe/g,x3/13,s6,pl/b,x5/7,pk/p,x12/4,pm/b,s4,x1/0,s13,x14/4,s5,x1/15,po/a,x11/0,s9,x10/3,s4,x7/4,pe/g,x9/6,ph/l,s3,x15/0,pa/c,x4/12,pi/m,x14/15,s13,pg/p,x0/3,s12,x13/9,s8,x6/15,s1,x3/1,s3,x10/5,pd/f,x12/4,s14,pm/a,s8,pg/c,x13/14,s4,x4/6,s7,x8/15,s5,x10/1,s6,x15/9,pd/b,x14/3,s3,x7/2,pm/f,x12/1,s14,pc/i,x5/8,s10,x12/7,s1,x11/9,s11,x10/2,s15,x12/14,s4,x1/0,s5,x10/13,pn/j,x15/4,s4,x13/8,pa/d,x15/4,s13,x2/14,pb/l,x5/12,po/n,x6/15,s7,x10/9,pa/p,x6/7,pg/j,x1/4,s14,x6/10,s12,x3/2,s13,x7/8,s9,x5/1,pm/n,x4/0,s6,x12/7,pp/i,x4/5,pk/f,x0/14,s7,x3/7,s7,x8/2,pp/e,x15/1,s11,x2/3,s4,pk/l,x11/13,s1,x0/2,s13,pd/g,x10/1,s10,x5/3,s13,x8/10,pe/o,x12/7,s2,x0/5,pn/g,x1/10,s14,x7/3,pp/d,x15/4,s12,pm/a,x5/10,pc/n,x0/7,s12,x6/3,s15,x1/2,s12,x12/0,s4,x11/4,s11,x9/15,pp/b,x8/10,pg/e,x5/4,s4,pa/h,x0/7,s1,x5/15,s4,x8/7,pf/i,x14/4,pa/e,x10/1,ph/i,x3/15,s13,x1/9,pn/k,x7/12,pi/j,x9/6,pk/p,x15/4,s13,x12/13,pn/f,x1/3,pd/e,x11/0,pn/c,x3/13,ph/o,x14/12,pa/j,x3/7,s13,x6/1,s10,x14/13,s14,x1/7,s8,x11/5,s3,x0/12,po/e,x4/7,s15,x0/14,s12,x13/9,s11,x10/15,pj/i,x8/1,pl/h,x6/3,s6,x1/15,s13,x7/12,pn/d,x1/10,s14,x11/4,s12,x0/2,s5,x13/6,pk/f,x0/5,s13,x10/4,s2,pb/l,s9,x2/1,pe/h,s7,x10/12,pf/k,x3/14,pj/c,x12/11,pl/a,x2/9,pn/k,x6/0,pe/c,s14,x2/12,s7,x10/11,s2,x9/2,s8,x15/4,pi/h,x2/11,s1,x0/6,pk/g,x10/7,s15,x0/11,s8,x8/10,s15,x3/6,pc/h,x10/0,pd/l,x12/4,pc/a,s3,x11/3,s14,x8/14,s5,x12/9,pl/d,x10/4,s5,x9/0,s14,x15/13,pp/m,x0/10,ph/a,x5/1,pi/c,x8/9,pm/d,x12/6,s1,x8/9,pl/p,x15/0,pb/m,x1/9,s14,x15/0,pg/p,x4/9,s14,x1/6,s15,x0/4,ph/k,x13/10,pf/m,x4/11,pe/b,x2/15,pj/f,x6/14,s8,x11/0,s13,x9/13,pa/d,x0/8,s1,x2/7,s2,x8/15,s6,x5/4,s4,x7/8,pg/m,x0/4,pc/i,x14/11,s3,x9/4,pp/o,x1/6,s10,x11/0,s14,x6/1,s10,x15/10,s11,x8/12,s8,x3/9,s14,x8/10,s2,x11/4,pl/h,x13/10,pk/d,x7/5,pi/m,s8,x14/9,pp/l,x12/0,pc/d,x5/4,pn/j,x7/12,pg/f,x2/13,s15,x8/15,s6,x9/12,pl/h,x0/3,s6,x13/8,s13,x14/1,s12,x5/3,s2,x11/6,s1,x13/15,s14,x0/5,s14,x1/15,s11,x4/8,pg/f,x15/0,pn/m,x4/12,s3,x7/2,pl/i,x15/8,s8,x1/13,s11,x2/6,s11,x13/0,s14,x15/8,pj/f,s6,x7/1,pm/p,x12/14,s15,x0/13,s14,x12/8,s8,x5/15,pg/h,x13/6,s5,x12/10,s9,x0/11,po/e,x7/4,s3,x1/13,s10,x7/4,s1,pk/i,x2/1,s6,x4/8,s15,x6/3,pa/b,x5/8,pe/c,x1/13,s12,x6/14,po/d,s2,x0/7,s1,x5/15,s2,x10/13,pk/g,x5/3,s1,x0/15,s3,x1/14,s14,x12/4,pp/o,x9/13,s11,x5/2,s3,x3/1,s14,x11/8,pi/e,x2/14,s14,x5/13,pg/b,x2/10,s6,x15/5,pl/o,x3/8,s3,x5/7,s3,x0/4,s14,x8/2,s7,x10/9,pa/b,x13/5,pm/i,x9/12,pn/o,x7/10,s1,x11/9,s14,x8/6,pc/h,x4/15,pe/n,x7/0,pc/j,x2/6,pp/m,x4/10,s8,x15/12,pn/k,x10/8,s9,x4/13,s1,x8/14,s9,x3/0,s6,pc/g,x11/1,pi/b,x5/9,s7,x1/4,s12,x14/0,s5,x7/12,pp/a,s6,x5/9,s5,x10/12,s1,x1/5,pm/k,x10/9,s8,x15/14,pg/n,x2/10,pa/i,x13/7,pm/j,s9,x0/11,s10,pp/h,x14/5,pd/i,x1/2,s9,x13/7,pg/e,s8,x11/5,pl/f,s8,x7/13,ph/p,x9/10,s13,x7/15,pf/k,x11/4,pd/o,x12/5,s12,x0/3,s15,x1/15,s4,x12/0,pb/i,x8/4,s9,x9/10,pm/e,x11/8,pa/g,x9/14,s4,pb/i,x6/10,s7,x5/4,pp/c,x1/13,s11,x11/9,pi/b,x10/8,pm/f,s13,x9/7,s2,x14/13,s11,pd/a,x6/4,s15,x11/13,s12,x5/7,pb/i,x6/4,s8,x14/2,s14,x13/12,s6,x3/9,s1,x4/11,ph/p,x14/9,pl/i,x8/13,pn/d,s1,pi/o,x5/10,pc/m,x14/4,s7,x10/13,pp/f,x15/2,s2,x13/5,s2,x1/14,pc/o,s7,x5/2,pm/g,x0/3,s9,x10/14,s2,x3/2,s15,x11/0,s4,x6/5,s11,x7/15,s9,x12/4,pb/k,x5/9,s2,x6/4,s4,x13/10,s6,x15/0,pg/j,x3/6,s9,x2/13,s14,x0/15,s7,x3/6,s9,pl/b,x1/10,s14,x15/13,s1,x11/1,s10,x4/10,s3,x12/6,s14,x10/14,po/c,x5/11,s4,x4/6,s2,x10/5,s6,x11/13,s11,x10/4,s6,x14/1,pb/p,x5/4,s8,x9/15,pe/a,x4/1,s12,x3/7,pi/m,x15/9,ph/o,x7/8,pi/g,x10/14,s5,x8/9,pa/f,x13/6,pi/g,s1,x0/2,s15,x3/12,s13,x1/7,pa/f,x8/9,po/b,x7/0,s1,x5/13,s4,x12/3,pf/e,s10,x13/9,po/g,x10/6,pf/c,x3/13,ph/j,s2,x5/8,s6,x13/3,s14,x10/6,s3,x1/4,pc/k,x7/8,s5,x15/11,pm/o,x0/10,pl/c,x15/9,pe/g,x5/4,ph/d,x1/3,pj/g,s4,x14/15,s14,x8/10,pi/p,x15/9,s6,x10/2,s14,x11/1,pd/f,x7/0,s10,po/k,x11/1,ph/j,x2/12,s5,x3/6,s12,x4/7,pb/c,s4,x3/14,s3,x5/12,s4,x11/8,s11,x7/13,s6,x9/8,s5,x5/0,s12,x15/10,s1,x9/5,s12,x3/15,s13,x14/7,s4,pg/e,x6/15,s3,pm/j,s9,x10/7,s8,x8/3,s11,x11/12,pn/h,x14/15,s4,x0/10,s11,x8/6,s13,pa/f,x5/9,s13,x2/6,pn/d,x12/1,s8,x3/0,s2,x5/15,s4,x9/10,pb/m,s6,pp/h,x6/0,s12,x15/13,s7,x14/6,s12,x2/5,s13,x4/8,s11,x10/7,pe/k,x5/13,s5,x6/14,pg/j,x15/4,s5,x2/6,s11,x9/8,s9,x3/2,s2,x11/9,pa/i,x8/10,s12,pk/o,x14/12,pb/h,x5/0,s5,x11/14,s14,x3/0,pf/g,x7/9,pb/h,x10/13,s15,x1/15,pi/e,x14/0,s13,x9/12,s15,x10/1,s7,x8/14,s2,x1/0,s12,x4/15,pd/p,x7/13,s8,x11/9,pl/k,x15/7,s14,x10/0,s9,x9/4,s10,x11/7,pf/g,x12/3,pd/o,x6/13,s10,x1/12,s14,x2/3,s8,x7/9,pm/b,x0/2,s6,x6/3,s10,x13/7,s3,x15/6,pf/h,s2,x4/0,pd/c,x14/13,s3,ph/j,x2/1,pm/i,x13/9,s5,x4/7,s11,x6/8,pc/g,x10/3,s14,x1/14,s1,x11/13,s4,x15/8,s14,x11/1,s4,x4/2,s7,x13/11,s8,x4/3,s15,x11/2,s6,x0/3,pe/l,x14/6,s2,x7/2,s14,x10/1,pj/c,x15/3,s2,pa/g,x13/1,s7,x12/3,s9,x10/6,s1,x8/15,s10,x13/2,pl/c,x8/0,po/n,x14/3,s9,pm/i,s9,x2/7,s8,x11/9,s9,x5/0,s9,x14/8,s14,x9/11,s7,x12/5,pj/o,x0/15,s10,x3/5,pp/d,x6/12,pn/i,x3/4,s9,x12/13,pb/k,x5/15,pd/p,x4/8,s1,x12/1,pi/f,x6/2,s8,x13/1,s12,x8/4,s9,x7/5,po/h,x10/4,s12,x3/2,s9,x6/4,s2,x11/3,s7,x0/1,s6,x5/4,s9,x13/9,s8,x10/7,s9,x11/12,pl/b,x1/8,s10,x7/2,s3,x14/8,pf/i,x0/2,pp/j,x4/1,pk/f,x8/15,po/p,x2/6,s9,x12/0,s12,x6/7,pg/l,x2/14,s14,x1/0,pa/c,x11/5,pb/n,x8/2,pm/d,x9/10,s1,x0/15,po/c,x5/9,pl/i,x12/13,pd/m,x11/6,s2,pf/n,x5/3,pg/l,x9/14,pe/m,x6/10,s14,x14/8,ph/i,x2/12,s8,pc/f,s12,x15/13,s12,x4/2,pm/j,x15/5,s13,x12/4,s9,x13/6,pk/p,x8/14,pc/d,s8,pn/k,x2/12,s14,x0/3,s8,x6/13,pc/o,x11/0,pe/g,x7/3,po/p,x6/2,pj/k,s8,x5/8,s14,x10/3,pd/l,s6,x9/2,s10,x4/3,s2,x2/6,s5,x12/13,s15,pk/i,x14/10,s11,x13/6,s14,x10/12,s8,x2/1,pc/o,x10/14,pg/b,x6/1,s14,x12/8,pp/l,s7,x11/9,pc/j,x7/4,s9,x3/8,pl/f,x14/6,s10,x2/1,s14,x5/9,pi/g,x8/6,ph/e,x1/13,s13,x11/8,pb/l,x9/3,s13,x1/0,pg/p,x10/5,po/l,x8/3,s5,x9/0,s12,pi/m,s12,x7/12,s12,x2/14,pd/j,x4/0,s1,x9/8,po/p,x15/5,s4,x2/10,s10,x14/9,s2,x1/0,s12,pb/i,s9,x9/10,s9,pj/e,x0/13,s10,x2/10,ph/p,x11/0,s4,x2/5,pf/o,x3/0,s13,x14/7,s12,x9/1,s14,x11/5,pa/d,x14/12,s2,x2/4,s4,x7/12,s14,x3/9,pf/b,x6/11,pp/o,x5/12,s10,x11/0,pf/h,x4/14,pg/j,x11/0,s14,x7/12,s9,x8/4,pc/d,x10/1,s1,x12/9,s3,x5/11,s7,x6/13,po/j,x10/14,s1,x7/1,s11,x6/15,s15,x8/13,s10,x3/10,pc/h,s11,pl/f,x1/9,s14,x6/8,pm/o,x1/5,pe/b,x15/4,pn/l,x1/7,s9,x13/10,pd/f,x1/11,pn/m,x9/15,s14,x11/1,s11,x14/5,pe/i,x1/3,pa/h,x12/8,s9,x11/1,s10,x9/6,s7,x14/4,pp/i,x10/12,s1,x0/14,pb/m,x4/12,pj/f,x9/15,s5,pc/i,x12/10,s15,pg/f,s12,pl/p,x3/11,pf/n,s9,x2/12,s8,x14/13,s11,x6/11,pg/l,x8/0,pe/k,x5/15,s15,x13/1,s6,x12/5,pl/j,x15/2,s14,x6/5,ph/i,s7,x11/10,pf/e,x4/0,s4,x2/3,pj/o,x6/15,s3,x14/13,pc/p,x11/9,s5,x8/1,pb/f,x7/0,s11,x4/15,s14,x13/7,s13,x14/0,s1,x6/3,pj/h,x14/4,pp/i,x7/3,s13,x11/14,s2,pn/f,x1/3,pl/j,x2/4,s6,x6/11,pi/e,x1/9,s4,x15/3,s12,x4/2,s14,x3/11,s7,x1/10,s8,x5/14,s8,x0/6,s5,x14/9,pm/g,s13,x2/1,s5,x3/15,pp/f,s13,ph/n,x1/10,pf/b,x9/15,pi/c,x7/1,s9,x12/8,s5,x15/9,pb/p,x7/2,s9,x8/0,s8,x5/13,pe/k,x2/11,pm/a,x10/12,s12,x7/3,po/c,s12,pe/b,x0/10,s3,x13/5,pf/i,x6/0,s6,x12/11,s1,x0/2,s8,x13/3,s9,x11/1,pl/h,x9/2,s2,x7/3,s1,x2/1,pf/p,x3/11,pn/o,s1,x2/6,s8,x12/14,s15,x0/2,pa/g,x14/11,s9,x15/10,s13,x14/8,pk/j,x13/9,s4,x10/5,pn/e,x13/12,s11,x8/4,pa/h,x11/1,po/l,x10/3,s9,x9/7,s12,x14/3,s5,x5/4,s6,x15/9,s6,x5/6,pn/p,x15/9,s8,x7/14,pc/h,x15/4,s6,x2/8,pa/g,x4/9,s6,x15/1,s8,pn/h,x10/8,pg/b,x15/6,pc/l,x7/10,s7,x5/9,s9,x8/14,s13,x9/7,s9,x14/11,s14,x4/0,s13,x6/3,pa/e,x4/5,s10,x1/15,s13,x3/12,s6,x2/13,s12,x7/9,s2,x13/4,ph/b,x14/10,s2,x15/7,pn/p,x0/8,s10,x10/5,s15,x13/0,s2,x15/1,s9,x13/11,s2,x3/9,s5,x13/0,ph/d,x14/12,pg/n,x9/6,pb/j,x10/5,pf/o,x3/15,s12,x9/6,s5,x3/1,s12,x10/2,s10,x14/8,pb/n,x0/2,s12,x7/12,pa/d,x6/4,s6,x9/11,s9,x12/0,s3,x4/9,s4,x13/5,pm/e,x8/14,s5,pi/j,x7/15,s1,pk/o,x3/6,s13,x7/10,s11,x9/2,pm/b,x7/4,s10,x0/12,po/j,x8/1,pf/k,x9/7,s6,pb/m,x13/12,ph/p,s3,x7/5,pg/j,x6/14,pi/d,s8,x10/2,s12,x14/12,s15,x6/2,pj/o,x4/10,pm/e,x0/11,s1,x13/1,pf/i,x0/2,s11,x10/4,s3,x0/8,s15,x12/1,s4,x6/3,s3,x12/5,s15,x2/13,s2,pn/p,x9/1,s10,x4/15,pe/o,x2/9,s8,x8/1,s7,x0/4,pn/h,s2,x13/15,s3,x2/14,s9,x5/8,pk/f,x9/2,s15,x12/11,pb/h,x9/4,pf/a,s14,x3/10,s13,x13/8,pe/j,x9/2,s3,x4/8,s5,x10/6,s2,x1/3,s3,x15/12,s2,x11/6,s5,x2/15,pp/f,x10/3,pd/k,s9,x11/9,s8,x10/1,s5,x6/13,pj/c,x1/2,s12,pg/k,x9/14,pi/d,x6/8,pk/f,s2,x15/14,s9,x12/4,pn/g,s1,x11/3,s10,x7/15,s8,x4/5,pa/j,x15/8,pd/l,x2/6,s3,x14/15,pg/o,x8/4,pa/m,x9/7,pn/e,x13/0,s6,x12/6,s14,x2/10,s14,x15/8,s3,pj/m,s3,x3/11,s8,x1/4,pg/k,x3/8,s15,x2/9,po/h,x1/14,s2,x9/15,s8,x5/14,pi/a,x3/15,pk/h,x11/7,pg/n,x4/8,po/i,x14/5,s14,x11/15,s5,x2/6,s12,x13/7,pa/j,x15/6,pl/e,x8/13,s5,x11/10,s15,x5/9,s15,x12/14,s10,x3/11,po/h,x6/8,s13,x0/9,pa/e,x10/11,pp/k,x0/12,s4,pe/a,x14/5,s5,x7/8,pf/h,s9,x14/13,s6,x8/2,s7,x6/9,s9,x7/13,s15,pl/n,x0/3,s2,x4/2,s10,x0/12,s1,x10/4,s2,x0/1,s14,x8/11,po/m,x7/14,s14,x11/3,s6,x4/9,s12,x14/12,s10,x7/11,s2,x6/15,s13,x0/14,pb/l,x4/7,s11,x6/2,s5,x8/0,pg/a,x9/10,s9,x1/12,s10,pi/f,x2/0,s8,x15/1,s8,x5/9,s14,x1/10,pc/h,x15/3,s10,x14/10,pl/i,x4/7,pp/d,x13/0,s14,x12/6,s14,x0/7,s9,x8/1,s7,x2/14,s3,x7/11,s6,x2/5,s6,ph/i,x7/12,s13,x15/2,pm/e,s2,x0/7,pa/n,x6/2,s11,x7/4,s3,x1/3,s15,x13/12,s14,x1/2,pf/j,x4/13,s13,x14/2,pi/n,x5/11,pe/k,x7/15,s9,x6/5,pi/l,x1/4,s1,x12/8,s12,x5/13,s6,x15/3,pp/g,x5/13,s5,pc/h,x6/8,s4,x0/12,po/e,x9/14,s15,x6/1,s6,x4/0,s3,x13/8,s4,x6/2,s11,x1/7,pa/l,x3/15,s8,x13/11,s7,x3/14,pc/k,x9/2,s2,x15/3,pl/g,x8/9,s2,pn/f,x2/5,s7,x15/3,s10,po/b,x9/12,s4,x5/3,pa/f,s3,pl/i,x6/11,s4,x1/5,pb/c,x7/6,s14,x15/10,s2,x8/4,s14,x13/14,s3,x0/15,s12,x13/5,s7,x10/8,s15,x7/3,s9,x5/12,pa/e,s11,x10/9,pc/i,x13/14,s13,x7/15,pe/g,s2,x3/6,pn/c,x7/0,s12,x1/11,s8,x8/7,s8,x0/13,pl/a,x8/5,pe/n,s10,pd/m,x6/9,s12,x1/14,s15,x3/5,pg/l,x7/11,s13,x0/6,s9,x15/5,s12,x8/6,pp/a,x14/1,s12,pe/i,x4/7,s10,po/h,x11/12,s13,x2/4,s4,x6/13,pa/l,s2,x3/11,s2,pb/d,x13/9,s4,x4/1,s1,x8/15,s10,x12/2,s5,x6/8,pc/h,x13/5,s6,x10/8,s12,x7/6,s8,x2/0,s9,x14/1,pg/p,x9/12,pj/h,x10/14,s8,x1/6,pp/c,x4/3,s2,x12/8,po/e,s4,x11/0,pi/d,x9/7,pm/e,x13/10,s14,x8/0,pa/n,x9/3,pb/p,x6/14,s14,x5/11,pl/f,x10/1,pn/i,x11/15,s13,x4/7,s13,x10/13,s9,x14/6,pk/g,x9/4,s9,x12/8,s13,x1/3,s6,x6/12,s8,x1/8,s7,x6/14,s15,pm/h,x15/8,s7,x12/4,pf/n,x15/13,pa/b,x12/14,s15,x6/8,s10,pn/c,x13/3,po/f,x1/6,pb/j,s1,x11/5,s3,x10/13,pe/o,s12,pj/h,x0/9,s12,x13/2,pk/d,s13,x10/15,s5,x8/1,s7,x15/11,s2,x4/14,s8,x10/3,s2,x0/6,s6,x14/12,pc/f,x7/10,pk/p,x11/4,s9,x7/14,s15,x1/9,po/f,x5/11,pd/e,x9/13,pn/i,x4/1,pk/m,x3/15,pl/i,x10/6,po/c,x7/1,pn/f,x12/14,s8,x7/2,s7,x14/0,s13,x7/5,s14,x1/15,s2,x5/6,s4,x2/8,s4,x13/12,s1,x10/5,s14,x6/1,po/h,x11/3,pe/n,x15/2,s15,x11/13,s1,x3/0,pg/m,x6/1,pe/a,x11/2,po/g,x9/7,s6,x4/14,pe/m,x2/3,pf/g,x15/7,s5,x11/0,s13,x4/13,pn/d,x6/15,s9,pb/o,x4/8,s7,x1/14,s9,x4/7,s6,x3/13,pi/c,x2/5,po/m,x12/15,pa/p,x13/8,s7,x9/4,pg/e,x8/6,s13,x1/15,pb/k,x9/2,po/a,s8,pl/e,x11/10,s2,pi/j,x9/3,s6,x10/15,s9,x13/4,pb/n,x1/14,pl/e,s15,x6/12,pn/a,x13/5,po/l,s1,x8/12,s5,x7/1,pp/h,x9/2,pl/e,x10/11,s13,x3/9,pc/g,x5/11,s8,x9/14,s5,x3/12,pn/p,x13/14,pk/e,x7/4,pl/a,x12/5,pf/n,x11/7,s15,x14/0,s7,x2/9,s3,pb/p,x12/8,s13,x10/2,s8,x8/14,ph/c,x10/5,pm/f,x14/2,pg/h,x5/3,s10,x15/12,pi/m,x10/3,pd/f,s6,x11/9,s1,x3/14,s13,ph/a,x6/5,s4,pj/l,x12/0,s10,x7/9,s2,x15/4,pp/a,x5/13,s15,x3/0,pk/d,x4/12,pe/b,x2/9,pd/g,x0/13,s2,x1/11,s3,x7/14,s1,x8/9,s15,x1/7,pi/n,x0/15,s5,x10/7,s5,pk/b,x8/0,s11,x1/13,s4,x3/10,pp/d,x14/2,s4,x3/9,s15,x1/12,s13,x14/8,s4,x7/0,s5,x3/14,pa/e,x7/12,s15,x14/1,pg/i,x6/10,s7,x4/7,s7,x2/9,s2,x13/12,pm/d,s14,pi/o,x15/0,s5,x9/12,s2,pl/n,x8/2,pa/o,x11/5,pm/p,s2,x4/9,pg/o,x15/13,pa/h,x3/6,pb/g,x14/4,s12,x1/10,pl/n,x7/4,s8,x12/9,pi/f,x3/7,s7,x0/14,s10,x1/8,s4,x11/9,pg/p,x12/4,s4,x9/7,s3,x6/1,s1,x5/14,s1,x4/0,po/k,x3/5,pj/l,x4/7,s4,x12/13,s7,x9/6,s5,x11/5,po/n,x9/7,s2,x13/14,s5,x8/11,s7,x7/0,s7,x1/13,s3,x6/9,pj/f,x13/3,pa/i,x7/14,pe/m,x3/15,s1,x10/13,s9,ph/o,x0/4,s9,x14/6,s5,x0/13,s1,x15/4,s11,x9/7,s7,x8/14,pf/m,x1/13,s7,x15/9,pe/g,s3,x7/10,s1,x11/4,pl/n,s11,x0/14,pm/o,s7,x4/9,s14,x2/12,pj/l,x1/13,s4,x2/9,pf/h,s5,x7/3,s13,x11/10,s15,x15/3,pa/o,s15,x1/9,pj/l,x14/3,s8,pc/k,x15/4,s11,x12/5,pp/d,s9,x13/14,po/f,x8/10,s12,x12/7,pd/m,s3,x2/13,pb/l,x9/5,pf/a,x7/8,s5,pn/i,x6/15,s14,x11/12,s7,x15/10,s7,x12/13,pa/j,x3/10,s5,x1/4,pe/c,x7/0,s8,x9/14,pb/m,x15/7,pc/l,x14/4,pg/b,x6/10,s3,x11/13,pe/j,x8/6,pl/h,s8,x3/10,s3,x13/2,s8,x11/5,s4,x15/8,pp/n,x2/9,s7,x13/5,s6,x12/6,pa/l,x3/10,pd/i,x4/6,pn/f,x9/8,pe/b,x6/3,s5,pf/c,x9/11,s3,pn/b,x4/14,pd/l,x11/8,s14,x0/9,s1,x4/1,pf/e,x3/2,pi/m,x11/15,pk/g,x12/14,pb/n,x10/8,s8,x2/3,s14,x14/12,s12,pd/e,x11/1,s6,x8/4,s6,x2/3,s6,x6/14,s15,x9/7,pa/m,x0/10,pk/d,s4,x14/4,s2,x12/0,pn/a,x3/13,pg/f,x14/15,pj/a,x12/0,s9,x7/6,s2,x2/3,s2,x7/6,pg/b,x0/12,s15,x4/2,s12,x15/7,s1,x6/2,s12,x0/12,s13,po/e,x2/3,s7,x13/9,s5,x6/14,s11,x11/2,s14,x7/15,s3,pk/h,x8/13,s3,x6/1,s13,x15/0,s6,x10/13,s14,x7/9,s14,pl/f,x13/6,s12,x4/11,s10,x12/8,pg/c,x2/9,s15,x3/1,s3,pa/k,x6/0,s10,x13/9,ph/l,x14/5,s3,x3/1,s6,x5/6,pj/a,x1/7,s2,x15/11,s4,x7/0,ph/d,x9/12,pl/e,x2/5,pn/c,x14/8,s3,x0/4,s5,x2/12,pe/j,x0/15,ph/p,x3/10,s11,pm/b,x4/1,s6,x11/3,s11,x0/7,s1,x5/12,pi/l,x2/7,s9,x5/8,s10,x14/3,s11,x13/4,s8,x15/0,po/k,x5/13,pl/m,x4/7,s3,x3/10,pg/o,x6/9,s5,x5/11,s13,x10/0,pc/d,s3,x12/6,pk/e,x2/11,s4,x9/4,s2,x13/2,s13,x0/14,s5,x6/5,pf/a,x14/11,s12,x15/0,s15,x13/11,s12,x1/8,pe/j,x7/6,s8,x5/1,s11,x7/9,s8,x4/5,s10,x10/14,s2,pl/p,x4/15,s9,x0/13,pc/k,x12/5,s8,x8/10,pe/a,x3/7,pc/f,x8/10,pm/b,x5/0,s9,x2/8,s3,x14/11,pa/d,x0/15,s9,x1/5,pj/i,x2/7,s5,x10/4,pf/o,x15/14,s1,x8/9,pn/a,x5/1,pk/j,x3/12,ph/l,x7/15,pf/g,x1/12,s4,x13/6,s10,x8/1,s14,x13/6,s4,pi/d,x12/9,s4,x14/5,s6,x3/10,s8,pp/n,x0/9,s5,x1/11,s9,x0/15,pa/k,x11/6,pj/b,x15/3,s15,x5/4,s13,x13/10,s7,x6/9,s15,x5/11,s4,x7/10,s7,x8/12,pk/p,s5,x13/7,pl/j,s3,x15/9,s1,x6/8,pf/i,x3/4,s7,x7/8,s1,x12/2,s5,x1/14,pj/m,x3/5,pp/n,x6/7,s14,x3/12,ph/c,x13/0,s10,x4/3,pd/f,x9/1,pg/a,x5/8,s4,x12/15,pd/k,x1/2,s14,x0/10,pa/e,x2/4,s13,x1/15,pi/k,x3/14,s15,x11/4,s4,x14/12,s12,ph/l,x2/7,pn/d,x5/12,pg/k,s12,x10/0,s6,x5/12,s4,pn/c,x7/10,s3,x12/15,pd/p,x8/9,s15,x5/14,pk/a,x4/13,pb/d,x9/2,s6,pi/l,x10/11,pb/n,x14/12,s15,x8/1,pl/h,x2/12,s9,pf/c,x8/5,s2,x4/10,s11,x12/13,s12,ph/g,s1,x1/10,s13,x2/8,pe/b,x10/9,pg/l,x15/14,pj/h,x13/8,pe/n,s4,x1/10,s13,pk/o,x0/4,pn/c,x12/11,pj/m,x7/1,pp/l,x6/10,pj/o,x0/11,s5,x15/12,pn/a,x2/6,s9,x9/14,s7,pk/o,x7/6,pf/b,x15/5,pj/n,x8/2,s3,x7/11,s4,x9/14,pk/h,x1/4,s8,x9/10,po/m,x12/8,s13,x15/9,s8,x2/8,s1,x14/11,s1,x5/8,s15,pe/g,s14,x15/3,s2,pd/m,x4/12,s4,x15/13,pb/l,x11/6,s6,x0/3,s2,x13/6,s3,x1/3,pa/k,x15/13,s14,x4/7,po/e,x14/13,s15,x11/10,s12,x12/5,pc/a,x11/13,pk/e,x6/12,pf/n,x10/8,pb/l,x15/9,s10,po/i,x14/8,s6,x2/13,s1,x15/10,pj/h,x9/6,s15,x4/7,pn/i,x12/2,s5,x15/9,pf/l,x3/13,s9,x11/15,s9,x13/12,s6,x1/15,pa/g,x0/10,s4,x15/11,s1,x10/3,s12,x4/6,s1,x15/14,s11,x1/3,s6,x7/5,s2,x0/4,po/l,x7/5,s8,x8/2,pk/m,x10/12,po/n,x9/7,s13,x13/14,s13,x4/7,pe/b,s1,x3/10,s6,x6/15,s12,x8/3,s1,x2/9,s3,x12/11,s6,x5/9,s3,x4/12,pn/j,x5/3,s2,x9/14,pi/g,x13/8,s8,x4/12,s1,x13/9,pd/l,x7/0,s8,x13/12,pm/o,x2/10,s6,x3/12,pg/c,x7/0,pj/b,x1/11,pi/m,x7/4,pb/d,x8/1,po/g,x13/11,s12,x2/9,pc/j,x7/4,s15,x10/2,pk/g,s10,x1/0,po/c,x8/11,pg/d,s3,x6/1,pl/a,x13/9,s2,x2/0,pc/j,x6/9,s1,x4/15,s12,x10/7,pl/f,x0/13,pi/n,x14/5,s6,x8/4,s4,x6/15,s1,x1/4,pj/f,x9/13,s13,x14/6,ph/o,x2/11,pe/d,x5/7,s10,pm/b,s7,x11/2,pi/o,x5/12,pa/n,x7/15,s8,x10/11,s2,x5/7,pc/h,x10/0,s7,x7/2,s8,x11/14,s11,x6/9,s4,x12/10,pd/k,s8,x8/14,s4,x4/6,s13,x15/14,s14,x8/11,pi/n,x4/14,pe/m,x3/11,s6,x13/1,s9,x15/3,s10,x2/0,pb/k,x5/1,pl/n,x12/11,pb/h,x4/9,pm/e,x6/13,s14,x11/4,s14,x8/7,s4,x12/13,s10,x6/1,pb/p,x15/3,s5,x14/0,s5,pf/c,s4,x13/12,s14,x5/2,s3,x13/1,s12,x14/8,s14,x3/1,s14,x5/0,s10,x10/6,s4,x1/5,s3,x9/10,s8,pm/j,x14/15,pp/b,x12/0,s6,x5/9,s7,x2/8,s14,x1/13,s9,x2/4,s6,x9/7,ph/l,x11/5,s6,pb/o,x8/13,s10,x4/10,s5,x13/6,s6,x9/1,s10,x3/12,s10,x4/7,s11,x6/15,ph/n,x11/12,pj/a,x13/7,s7,pf/n,x12/6,pm/b,x14/11,s1,x7/9,pl/f,x15/8,pd/g,x11/3,s10,x1/0,s2,x10/3,s1,x15/1,pb/f,x3/2,s13,x13/9,pj/k,x3/11,s7,x10/5,pp/c,x2/4,s10,x1/11,pj/b,x8/6,pg/p,x7/14,s4,x15/6,pa/i,x10/8,pd/h,x5/14,s14,x11/0,pf/l,x13/5,s11,x3/8,pm/d,s11,x4/9,po/k,x0/3,pj/e,x15/11,s10,x12/13,s7,x4/2,pl/k,x7/14,pa/n,x3/0,pj/b,x14/11,pp/a,x0/4,ph/m,s10,x14/9,pa/l,x1/8,po/n,x9/14,s10,x6/5,s11,x2/10,pe/p,x3/9,s10,x6/2,s3,pl/m,x5/10,s3,pn/a,x15/1,s2,x6/10,pd/b,x11/8,pa/p,x5/3,pb/h,x2/12,s8,x7/14,s6,x3/8,pg/e,x6/0,s4,x13/15,pj/k,x0/3,s5,x11/5,pi/b,x9/6,s7,x12/11,pe/d,x6/14,ph/p,x7/4,s4,x6/8,pi/m,x0/2,s5,pb/l,x1/3,s11,x7/6,pp/f,x8/15,s14,x11/5,s4,x12/14,pk/n,x11/9,s6,x3/5,ph/g,x2/1,pi/o,x8/10,s9,x15/13,s2,x2/7,s9,x1/8,s9,pg/k,x15/4,pl/b,x14/8,s3,x13/1,pn/m,x12/11,s5,x4/3,pe/c,s7,x5/14,s11,x12/4,s7,x11/0,s10,x14/1,s11,x8/15,pg/h,x9/13,s1,x5/8,pn/k,x4/12,pm/l,x14/6,s11,x4/15,s5,x14/13,pj/i,s12,x7/12,pc/a,s7,x5/4,pe/d,x1/2,s10,x8/7,s9,x15/11,pf/b,x7/12,s8,x8/2,s4,x0/11,s15,x6/5,s12,x15/1,pa/m,x5/8,s13,pl/h,x11/7,s10,x12/8,s2,x10/11,s13,x1/4,s1,pc/g,x11/10,s11,pi/l,x8/4,s14,x0/13,pb/n,x10/8,s3,pi/a,x3/12,s15,x15/0,po/h,x11/4,pm/n,s13,x15/13,s11,x8/9,s5,x5/14,s7,x6/10,s13,x12/15,s6,x4/11,s10,x13/6,pa/h,x12/1,pb/l,s11,x3/9,s7,x10/14,pc/d,x9/11,pm/k,x0/14,s1,x8/2,s15,x0/12,s13,x1/7,s9,x3/11,s13,x8/10,s3,x14/6,s11,x15/8,s10,x5/2,pf/h,x15/0,s15,x8/2,s13,x7/3,s10,x14/15,pa/k,x9/2,s4,x0/5,pg/b,s11,x2/3,s10,pd/m,x0/14,pk/l,s8,x1/9,s3,x13/0,s8,x3/8,pg/n,x5/6,s11,x7/1,s2,x5/8,pd/c,x12/14,ph/j,x11/7,s7,x3/14,pf/i,x2/0,s2,x6/7,pm/d,x3/13,pe/k,x6/10,pf/a,x13/3,pm/b,x8/5,s12,x7/10,s15,x8/12,pd/a,x14/10,s14,x2/12,pe/c,x1/7,pm/i,x4/11,s3,x6/0,pf/j,x10/15,s15,x0/3,pb/d,x4/14,s5,x11/8,s8,x10/5,pm/c,x1/12,s8,x14/2,s6,x0/9,s12,x15/7,s7,x1/11,s12,x13/5,s10,x15/1,po/h,x4/13,pe/k,x3/1,pj/a,x6/14,s10,pg/f,x10/7,s7,x1/5,s8,x15/0,s11,x1/6,pi/m,x0/15,s6,x8/9,pa/l,x3/6,pp/o,x14/8,s2,x15/7,s7,pa/h,x2/1,s10,pd/n,x15/7,s4,x11/0,pj/f,x12/9,s5,x5/11,s13,x4/1,pd/c,x2/14,s14,x5/11,s10,x15/10,pe/k,x9/14,pp/a,s8,x6/10,s10,x0/11,s11,x15/8,pd/c,x9/10,s15,x5/2,s13,x13/0,s7,pj/e,x1/14,pk/p,x13/8,pm/c,x11/3,pk/l,x5/13,pp/c,x12/8,s15,x6/11,pg/h,x14/15,s2,x6/11,pj/c,s10,x0/13,s13,x6/15,s2,x14/7,s1,x0/5,pk/d,x4/14,pg/j,x9/8,s1,x3/12,s7,x13/11,s11,x14/4,s3,ph/o,x7/13,s1,x9/0,s10,x15/1,s12,x13/5,pc/l,x11/6,s7,x8/0,s13,x2/9,s9,x4/3,s8,x1/15,s9,pf/o,x7/10,s1,x5/11,s2,x15/8,pk/m,x5/7,pb/n,x3/4,s15,x8/15,ph/i,x9/5,s7,pn/m,x11/12,pc/b,x3/13,pd/i,x4/8,pa/n,x5/0,s12,x3/9,pi/b,x1/7,s9,x4/2,pn/a,x8/3,s3,x14/7,s2,x13/15,s13,x0/10,pi/b,x11/7,s1,x6/5,pf/a,x0/4,pm/e,x3/1,s10,x12/6,s8,x13/1,s10,x0/3,pl/f,x15/13,pd/m,s10,x4/12,s14,x3/10,s9,x0/7,s15,x6/4,pi/l,x11/2,pn/p,x15/10,po/d,x4/14,s9,x3/0,s5,x14/9,s12,x13/10,pj/l,x15/1,s7,pf/b,x8/0,pa/c,x13/5,s6,x9/11,s10,x2/5,s6,x9/8,s1,x0/7,s2,x3/8,s15,x9/11,pd/p,x4/3,s10,x0/8,s11,x7/2,pi/j,x3/0,pp/g,x1/15,s10,x13/14,pf/j,x10/8,pa/d,x2/11,pn/f,x13/7,s14,pi/m,x5/12,pp/n,x7/14,s10,x5/4,pc/m,x8/6,s2,x10/3,s3,x13/5,s15,x6/1,s1,x5/7,s10,x11/13,s10,x6/3,pe/o,x0/13,s4,x4/5,s1,pp/j,x8/2,s2,x14/0,s2,x13/8,s10,x4/7,pe/b,s4,x3/10,pn/m,x9/5,s15,x2/3,pf/h,x11/10,pp/o,x14/3,s2,x7/5,s11,x10/15,s2,x11/14,s2,x1/6,s13,x12/7,s13,x1/8,s1,x10/14,s9,x9/3,s4,x4/14,s3,x6/15,pa/h,x1/9,s5,x5/12,pb/m,x9/14,s9,x1/0,s14,x9/4,po/j,x7/8,s10,pn/f,x13/9,s13,x7/2,pp/e,x10/5,pg/l,x15/12,s4,pa/o,x7/3,pe/k,x2/13,s7,x11/10,pa/b,s5,x9/0,s10,x13/14,pn/j,x4/3,pg/l,x8/5,pa/j,s11,x15/10,po/g,x1/7,s10,x12/10,s1,x3/5,s2,x2/7,s7,x15/4,ph/c,x0/1,s5,x11/4,pf/m,x14/5,pa/p,x4/10,s1,x11/0,s15,x2/10,pe/b,x4/12,s9,x0/10,pn/k,x1/14,s10,x11/3,s7,x1/5,s13,x11/6,s7,x8/2,pi/g,x15/1,s4,x8/0,s10,po/k,x5/6,s5,x4/13,s11,x10/2,pc/i,x7/14,s7,x12/6,pj/d,x14/10,pb/o,x15/4,pe/f,x7/11,s11,x2/1,pn/o,x0/4,s5,x5/14,pk/a,x10/13,s7,x8/11,pd/m,x15/5,s6,x6/9,s14,x1/11,pg/j,x12/8,s4,pl/i,x3/13,s13,x2/8,s12,x5/15,pk/p,x3/9,pl/b,x10/4,s10,x5/1,s12,x2/8,s3,x5/9,s3,x15/12,s9,x5/10,s10,x12/1,s1,x5/10,pf/h,x13/0,po/d,x6/3,s8,x8/4,s13,x1/15,s11,x6/14,s1,x15/9,s11,x13/12,pa/j,x9/10,s15,x2/7,s4,x11/9,s10,x12/5,s12,pm/n,x8/6,s12,x7/9,s7,x3/10,s4,x6/7,ph/e,x11/0,s12,x2/15,s5,x5/11,s13,x13/7,pb/c,x14/6,pe/i,x0/4,s9,x1/12,s1,x14/9,pn/f,x15/7,s1,x4/9,s11,x6/15,s6,x1/13,s2,x12/10,s3,x7/9,s8,x1/6,s1,x2/5,pe/b,x1/6,s15,x3/12,pl/g,x5/10,pe/f,x8/2,s6,x1/4,pb/p,x13/0,pf/k,s9,x7/4,s5,x3/1,pp/a,x6/10,s2,x13/0,s13,x12/9,s2,x0/11,s7,x12/10,s2,x5/6,pi/l,x3/4,pk/n,x12/0,pl/p,x7/3,s10,x11/0,s12,x9/15,pi/g,x10/6,pl/j,x0/5,pi/a,x8/1,s6,x7/11,s9,x13/14,s1,pk/g,x7/10,pl/n,x9/2,s11,x8/7,ph/k,x3/1,pm/c,s10,x11/12,s5,x13/4,s8,x5/15,pj/o,s10,x1/0,s1,x15/10,s6,x13/6,pd/e,x7/11,s15,x0/3,pm/p,x7/4,s4,x12/1,s8,x2/10,s8,x12/15,s3,x5/9,pb/a,s4,x1/2,po/m,x11/4,s2,x13/6,s1,x12/8,s8,x13/7,pf/l,x9/10,pa/d,x1/15,pi/g,x7/6,s2,x15/1,s7,x10/8,pb/e,s2,x12/2,s8,x7/8,s14,x11/15,s1,x13/3,s9,x5/8,pj/a,x9/3,s8,x6/13,pn/c,s5,x5/9,pi/l,s7,x6/7,pm/p,x14/9,s2,x6/5,s9,x1/13,pj/g,x15/12,s5,x5/13,pp/i,x15/3,pe/b,s7,x2/14,ph/d,x15/11,pc/j,x1/12,pp/n,s14,x0/8,s6,x7/3,pi/k,x2/13,ph/n,x14/4,s4,x3/6,pg/l,x7/5,s3,x15/4,s7,x13/10,pi/p,s15,x5/0,s14,x15/11,s4,pd/l,x5/10,s9,x12/3,s8,x1/5,s5,x2/3,pm/c,s14,x12/11,pg/i,x5/13,s15,x12/1,pm/b,x0/10,s12,x8/4,pa/p,x12/3,pj/e,x9/1,ph/m,s13,x6/7,pa/b,x15/13,s7,x11/7,s10,x14/4,s14,pi/o,x7/10,pm/d,x4/15,pp/g,x10/11,pj/b,x15/8,s5,x6/0,s7,x7/11,pp/c,x5/14,s8,x4/12,pl/o,x14/5,s3,x6/9,pn/g,x15/3,s2,x0/1,s3,x4/10,s4,x13/15,s3,x14/1,s5,x6/15,pe/f,x5/0,s12,pd/l,x10/3,s4,x5/15,s9,x11/2,s12,x6/4,s14,x9/7,ph/c,x8/5,s2,x1/0,s4,x11/7,pp/b,x10/4,pk/n,x13/3,s11,x11/14,pi/e,x3/13,s3,x2/0,po/m,x8/7,s8,x14/5,pg/p,x2/11,s6,x14/15,pm/a,x1/12,pp/o,x13/11,s5,x9/5,s11,x7/14,pf/b,x13/9,s11,x5/11,s4,x6/12,s14,x11/3,s13,x4/8,s5,x9/0,pc/k,x7/12,pj/m,s13,x13/15,s4,x10/12,s13,x11/5,ph/o,x10/12,s7,pf/k,x0/11,s2,x14/2,s13,x9/12,s4,x15/10,pd/g,x5/8,pf/n,x9/15,pa/h,x8/12,pb/n,x14/5,pi/a,x10/4,s6,x0/9,s9,x14/4,pp/k,x2/11,s13,x0/15,s6,x2/14,s3,x13/3,s10,x4/8,s7,x10/1,s1,pf/g,s15,x2/13,s8,x3/12,pp/i,s8,po/a,x4/11,s8,x8/13,pp/d,x14/11,s9,x13/10,s8,x4/12,s10,x1/5,pf/g,x10/11,pi/e,x8/4,s7,x7/11,pj/o,x9/5,s7,pp/b,s3,x1/8,s11,x0/10,s4,x12/9,pe/l,s3,pc/j,x0/8,s10,x4/12,s14,x5/14,s4,x7/11,s14,pf/k,x10/8,s13,x13/5,s8,x8/12,s5,x11/13,pa/c,x7/12,s14,x6/8,s5,x7/2,s14,x9/3,pn/g,s6,x13/6,pi/e,x11/3,pj/a,x5/2,s2,x15/13,s11,x6/12,s2,x10/3,pg/l,x5/0,s4,x1/2,s1,x15/4,pf/n,s10,x12/6,s3,x1/11,s13,x10/13,s14,x3/8,s10,x4/13,pk/m,x10/0,s7,x15/14,pe/n,x12/1,s2,x4/14,s14,x11/7,s14,x15/9,s14,x14/11,s1,x0/3,s13,x4/12,s8,x0/7,s9,x3/1,s1,x6/14,s11,pi/c,x1/8,pk/a,x2/14,s9,x11/10,pl/c,x5/15,pf/d,x1/13,pm/e,x2/12,s10,x5/10,po/g,x7/11,s5,x8/2,s5,pp/j,x3/4,s10,x11/0,pf/b,x12/2,s13,x9/15,pe/k,x11/12,pb/i,x1/6,po/c,x3/11,pj/b,x14/0,pm/c,x3/4,ph/i,x7/12,s7,x5/6,s1,x1/0,pc/k,x6/4,s3,x12/9,pd/l,x7/1,s2,x13/3,s1,x11/2,s15,x14/9,s5,x1/11,pp/f,x12/2,pi/d,x14/9,po/m,x4/12,s7,x11/9,pn/a,s4,pm/l,x4/13,pi/n,x8/10,po/m,x5/13,pd/p,x12/7,s14,x5/14,s11,x3/6,s11,x10/14,s10,x9/12,s6,x6/4,pj/m,s12,x13/8,s13,x0/14,pb/o,x11/5,pk/h,x10/1,s10,x11/8,s12,x12/9,s4,x10/5,s14,x2/8,pn/o,x4/14,s4,x9/1,pe/f,s9,x2/13,s7,x15/11,pc/i,x3/7,s13,x4/2,s4,x7/10,s9,x3/11,s14,x5/8,s15,x7/11,pd/g,x13/6,s3,x12/0,s6,pi/c,x6/7,ph/m,x13/1,pk/e,s15,pa/g,x14/15,po/j,x5/11,s15,x9/14,s9,x5/12,pi/e,x10/3,pn/o,x11/1,pi/c,x7/5,s6,x3/9,s14,x15/13,pk/a,x6/5,s7,x14/15,s10,x4/5,pg/i,x2/9,s1,x3/6,pl/m,x10/14,s9,x4/15,s15,pp/o,x9/7,pc/d,x12/3,s7,pe/n,x13/4,s15,x3/6,ph/g,x5/10,pf/n,x11/12,s11,x4/15,po/j,x0/14,pi/d,x6/3,pe/h,s6,x15/2,s8,x5/6,s7,x14/12,pm/c,x10/13,po/l,s10,x4/15,pd/e,s1,x6/11,s8,x9/3,pl/o,x4/7,s14,x11/6,pc/k,x2/7,s11,x1/14,s5,x2/15,s10,x9/5,s3,pa/b,x0/4,pg/m,x13/3,s10,x14/11,s15,x0/4,pc/h,x3/7,s6,x6/1,pe/a,x2/15,s15,x5/0,pi/p,x11/12,po/g,x6/15,pa/j,x3/0,s14,x11/14,pk/p,x1/6,s12,x4/12,pm/g,x3/11,pb/a,x1/7,s3,x14/0,s6,x8/3,pe/n,x13/14,s12,x12/3,s4,x14/4,s11,x3/9,s7,x6/7,pk/o,x2/14,s13,x11/7,pn/d,x10/3,pf/b,x4/7,s10,pc/m,s6,x5/2,s13,x3/0,s7,x6/9,pd/n,s9,x10/1,s11,x12/15,s9,x10/14,pe/o,x0/12,s1,x7/13,pg/i,x1/5,s12,x9/15,pp/k,x13/10,ph/m,x11/9,pc/o,x15/6,pa/h,x12/11,s5,x13/2,pe/c,x0/15,s2,x6/3,s8,x4/15,s14,x2/12,s3,x1/13,s5,x10/4,s10,x12/6,s14,x5/4,pl/d,x13/7,s12,x5/9,s1,x2/10,s15,x14/15,ph/c,s14,x5/8,s4,x15/11,pa/b,x0/1,s3,x2/4,pe/l,x5/12,s2,x13/11,pj/p,x6/3,s2,x4/12,s7,pg/k,x9/10,s9,x7/5,s6,x6/12,s6,x4/1,s2,x7/6,s6,x9/13,s3,x15/7,pd/m,x6/14,s1,x0/10,pa/j,x8/3,s12,x5/0,s10,x6/1,pb/h,x3/8,pj/m,x15/13,pf/a,x7/9,s5,x3/5,pm/p,x12/11,s6,x15/13,s7,pn/j,x11/8,pc/e,x10/14,pn/h,x8/7,pj/k,x13/15,s15,x6/7,pg/f,x1/0,pb/l,x13/7,s10,x10/1,pk/n,x7/13,s10,pd/f,x5/9,pn/l,x10/8,pd/c,x0/2,pf/l,s4,x3/13,s11,x8/10,pk/i,x4/13,ph/j,x8/10,pb/e,x2/12,s5,x6/9,s8,x1/3,pc/n,x8/6,pd/g,x9/7,s7,pa/l,x13/4,s15,x5/2,s5,x9/10,pb/o,x3/7,pn/g,x10/6,pa/d,x13/4,s9,x2/11,s3,x15/3,s7,x13/11,s13,x0/8,pg/l,x9/4,s15,x10/1,s3,x14/0,pa/j,x1/8,s14,x3/11,s15,x2/15,s6,x4/5,pl/e,x8/6,s8,pk/o,x1/2,s6,x7/6,s6,pg/d,x0/11,pp/e,x4/14,s5,x11/10,pj/f,x4/2,pe/d,x8/13,s1,x7/14,s7,x12/6,s12,x13/7,s5,x10/1,s3,x12/8,s14,x13/9,pc/b,x14/8,s6,x4/0,s5,x6/9,pm/a,s1,x1/2,pc/g,x14/12,s8,x3/10,pn/b,x0/4,s1,x5/2,pl/f,x6/3,ph/o,x14/2,pl/p,s12,x1/7,s14,x14/10,pe/j,s14,x8/5,pc/n,x9/13,pi/a,x4/2,pb/m,x14/12,pe/n,x2/9,s8,x13/15,pj/f,s4,x6/3,pb/l,x9/2,s8,pc/f,x7/12,s3,x3/8,pl/h,x9/0,s4,x15/6,s13,po/g,x5/14,s5,x13/3,s9,x0/11,pl/c,x8/2,s1,x1/9,s13,x3/11,po/a,x2/5,s14,x7/0,s1,x8/11,s1,x14/4,s11,x1/5,s8,x15/12,s3,x0/9,s4,x8/12,pf/i,x10/9,pn/b,s4,x12/15,pk/c,x3/2,s11,pd/b,x4/5,s14,x7/11,pl/n,s15,x4/9,s8,x7/1,s2,x0/15,pb/g,x5/9,pd/i,x15/3,s15,pl/c,x5/4,s2,x13/11,s5,x6/9,pe/a,x13/12,pp/h,x14/9,pk/i,x8/5,s14,pa/o,x15/0,pg/d,x13/12,s12,pe/i,s11,x4/2,pd/c,s3,x12/10,pk/f,x7/6,pn/e,s10,x11/10,s6,x1/2,pi/c,x12/8,po/e,x9/6,pm/i,x0/5,s5,x3/1,pp/h,x6/9,pk/e,x8/5,pn/o,x15/9,pb/f,x4/7,s1,x3/0,pi/j,x7/14,pk/p,x1/9,pb/c,x7/13,s2,x9/8,s15,x6/5,pf/n,x12/8,pl/a,x14/3,s12,x5/8,s3,x11/9,s10,x2/15,s11,x4/8,pm/e,x15/14,pi/k,x7/11,s3,x8/4,pc/d,x1/9,s13,x12/14,pi/p,x11/4,s6,x1/8,pl/g,s3,po/j,s12,x10/15,s4,x6/7,s8,x14/3,pm/k,x8/11,pf/c,x4/14,pn/k,x5/0,s3,x10/13,s6,x0/8,s11,x1/3,pl/b,x13/7,s5,x6/4,s11,x10/3,s11,x14/5,s8,x13/11,pa/p,x2/12,pl/m,x3/8,s12,x12/11,s2,x6/4,pe/h,x7/11,pa/i,x14/12,s13,x10/13,s5,x4/6,s3,x2/7,s2,x11/3,pm/l,x13/12,s15,x9/14,pf/n,x2/7,s6,x9/8,pi/p,x6/4,pa/h,x3/14,po/e,x0/1,s10,x5/14,s14,x0/15,s11,x14/9,s10,x1/2,s13,pm/p,x4/11,s3,po/l,x8/1,s7,x13/12,pc/k,x6/2,s11,x10/13,po/l,s6,x14/7,pn/b,x2/12,s14,x5/6,pf/o,x13/14,pm/h,x8/3,pg/b,x12/14,s10,x13/1,s8,x14/15,pd/a,x13/5,s7,pc/f,x8/6,s14,pe/d,x9/15,pp/o,x13/12,pl/a,x14/7,s10,x2/12,s2,x11/7,s13,x0/8,s6,x10/1,pp/e,s10,x4/7,s9,x11/15,s1,x4/5,pk/l,s1,x10/14,s5,x0/1,s7,x9/10,ph/n,x2/14,s9,x15/12,pj/f,x9/6,s10,x4/10,pb/m,x0/7,pc/g,x14/13,s10,x9/6,s8,x5/12,s14,pe/m,x1/15,s8,x8/13,s9,x11/1,pg/c,x10/2,pb/l,x15/0,s15,x4/6,pm/f,x7/13,s9,x8/15,pi/p,s4,x3/2,po/j,x11/1,s8,x6/4,ph/i,x14/0,pa/e,s6,x2/1,ph/p,x10/8,s4,po/g,x5/15,pk/p,x2/10,s6,x0/15,pf/d,s10,x2/14,pl/m,s6,pj/c,s8,x6/3,pn/o,x8/12,pm/h,x2/9,pb/c,x4/14,pp/d,x8/5,s13,x2/6,pi/j,x7/13,s14,pe/d,x14/3,s13,pb/p,s9,x6/0,pn/d,x9/5,pl/p,x3/7,pd/h,x14/11,po/n,x15/0,s10,x4/1,s11,x13/12,s10,x5/10,s14,x1/15,s15,x12/10,pf/d,s1,x6/0,s6,x15/4,s7,x5/11,s6,x8/4,s12,x5/3,s2,x8/1,s10,x9/15,s3,ph/o,x2/13,pn/l,x9/12,s8,x3/1,s2,x4/13,pa/e,x11/12,s6,x10/0,pm/o,x9/4,pn/f,x15/3,s2,x10/14,pg/h,x3/9,pp/k,x7/5,s1,x11/15,pc/l,x14/4,s13,x8/3,s6,x10/6,s15,x13/4,s15,pm/o,x5/3,s15,x6/12,s5,x15/4,pa/p,x14/7,s10,ph/c,x4/1,s13,pj/k,x0/8,s5,x7/9,s9,x10/5,s7,x12/8,s1,x6/15,s3,x8/4,pb/m,x2/1,pf/h,x14/4,pa/n,x10/5,pl/b,x12/14,s4,x10/9,pp/j,x5/1,s11,x2/11,s11,pe/o,x4/15,pj/h,x8/10,s3,x1/12,pc/i,x3/7,s2,x4/6,s4,x9/13,pp/h,x1/15,pm/f,x8/5,pg/i,x15/14,s14,x9/11,s10,x14/13,s2,x15/7,pf/n,x0/3,s10,x4/9,s1,x10/14,s12,x13/7,pd/p,x11/14,s14,x4/13,pc/b,x11/2,s7,x1/4,pg/j,s3,x3/12,s11,pb/n,x6/15,s6,x4/7,pk/o,x2/1,s13,pe/n,x12/9,pp/o,x4/7,s15,x8/14,pn/b,x15/7,s14,x4/12,pm/e,x3/6,s7,x2/4,pk/f,x12/10,s2,x4/3,pl/e,x13/14,pb/k,x15/7,pn/d,x8/5,pi/f,x11/1,pa/g,x9/7,pi/j,s11,x4/6,s12,x5/1,s1,x2/8,pf/a,x11/13,s9,pg/e,s5,x2/1,s13,x8/9,s12,x13/2,po/h,x9/8,s8,x14/1,pc/k,x0/9,s7,pm/l,x10/12,s6,x8/7,s8,x5/2,po/p,x0/11,pa/k,x6/1,s10,x11/2,s5,x9/0,pc/p,x4/12,pg/a,x3/10,po/b,x7/2,s3,x12/5,s2,x9/0,pj/c,s6,pb/f,x10/7,s8,x4/11,pi/o,x13/15,s2,x4/14,s10,x7/1,s1,x8/13,pc/b,s6,pk/m,x9/3,pi/o,x6/14,pc/f,x8/2,ph/g,x13/9,pk/b,x3/5,pc/a,x1/6,s15,x7/11,s15,x8/5,s11,pp/j,x12/14,pi/e,x13/0,pd/f,x2/11,s8,x1/12,pi/k,x11/10,s15,x13/4,s1,x9/1,pg/d,x12/5,s2,x9/10,s9,x8/6,s8,x1/2,s10,pp/o,x8/3,s14,x0/11,pd/a,x4/2,s13,x8/9,s9,x1/12,s15,x6/0,pj/g,x7/3,s6,x12/9,s15,x1/14,s11,x0/7,pa/d,x9/10,s5,x8/3,s14,x0/15,s7,x5/8,pl/j,x4/1,s15,x6/0,pn/b,x2/11,pm/h,x15/6,s4,x11/13,s6,x3/7,po/g,x14/10,s1,x1/6,s4,x13/0,s5,x8/11,pj/c,x5/1,s2,x3/15,s2,x11/12,pi/g,x0/14,pd/l,x1/13,s6,x0/4,s13,x3/11,pf/e,x1/4,pg/c,x10/6,pn/e,x2/7,s8,x3/1,s8,x14/15,pb/g,x4/13,pc/d,x6/3,s15,x8/7,s12,x11/14,s13,x10/8,s4,x5/14,s8,x1/10,pm/i,x0/15,s7,x6/13,pk/h,x11/1,s9,x0/2,pe/a,x5/4,pm/b,x11/9,pi/p,x6/13,s12,pc/m,x14/12,pf/b,x3/4,pk/o,x9/8,s3,x7/2,s5,x11/8,s5,x1/7,s14,x15/13,s4,x10/6,s15,x14/15,s8,x11/0,pe/l,x15/10,po/k,x7/0,pb/g,x5/11,s5,x12/10,s5,x2/11,pa/i,x10/7,s15,x12/3,pm/g,x13/7,pl/e,x1/12,s12,x8/7,s14,x0/14,s8,x10/11,pj/m,x15/8,pf/p,x13/9,s14,x10/1,s11,x3/2,pb/d,x11/12,s10,x6/5,s7,x3/0,s11,x8/11,ph/a,x10/13,pc/d,s5,x11/3,s9,pi/n,x14/13,pg/o,s11,x7/6,ph/l,x13/15,s8,pk/f,x3/8,pp/e,x13/15,s2,x1/5,s4,x15/14,po/j,s9,x2/3,pn/e,s12,x4/14,po/c,x9/8,pg/e,x3/13,ph/l,s10,x4/15,s13,x1/9,pj/m,x4/11,pg/i,x8/14,s4,x15/3,pd/a,x10/8,pi/n,x2/5,pp/m,s8,x7/3,pb/e,x11/10,s6,x9/7,s4,x13/2,s11,x9/3,s3,x13/15,s7,x0/7,s2,x11/1,s1,pf/j,x5/10,pa/i,x1/11,s10,x6/7,s2,x2/4,s11,x9/14,pe/n,x5/1,s9,x13/3,s7,x14/4,s8,pl/o,x10/2,pm/f,x4/3,s3,x13/8,s10,x12/15,pj/h,s7,x9/2,s3,x11/10,s13,x5/3,s15,x8/13,s10,x10/0,pe/n,x7/8,s14,x2/14,s9,pb/f,x4/15,s6,x10/8,pe/p,x15/11,pd/l,x13/6,s8,x5/9,s9,x4/11,s10,x1/12,s1,x15/10,ph/i,x13/0,pd/l,x1/14,pa/p,x2/8,pn/f,x4/6,s3,x12/3,s7,x15/4,s7,x14/11,pd/j,x1/2,s1,x5/6,pi/a,x10/8,s7,x15/2,s1,x13/11,s8,pg/f,x12/2,s9,x10/5,s2,x2/13,pk/d,x9/3,pm/e,x12/5,s8,x2/14,s15,x5/13,s1,x8/4,s12,x15/11,pb/a,x14/10,s4,x12/4,s3,x11/3,ph/l,s9,x8/12,s4,x11/15,s5,x12/1,s2,x14/6,s14,x0/5,pf/m,x15/10,pi/c,x1/9,s4,pd/n,x3/5,s3,x14/6,s13,x8/3,pe/i,x12/6,s4,x13/1,s8,x8/3,pf/c,x1/9,s6,x7/12,s13,x11/8,pe/a,x9/0,s5,x8/14,s5,x9/7,s11,x3/0,s11,x8/14,s15,x5/1,s13,x4/6,pj/p,s13,x9/0,pf/i,x5/13,s14,x0/8,s11,x5/1,pc/a,x14/8,ph/l,x11/13,pf/c,x14/2,s2,x5/10,s12,x7/2,pg/m,x9/8,s9,x14/3,ph/k,x13/9,s1,x1/2,pj/p,s1,x14/5,s1,x0/1,pi/d,x4/3,s3,x10/13,s6,x14/11,pn/b,x3/10,pi/d,x2/1,s12,x10/4,s11,x14/2,ph/f,x5/1,s4,x14/3,s2,x15/13,s13,po/g,x11/12,s15,x14/10,s1,x15/11,s3,x6/2,s13,x15/7,s12,x5/14,s14,x3/2,pf/m,x13/12,pp/o,x9/4,s5,x1/13,s11,x3/7,s14,x5/9,pa/b,x7/0,s8,x6/15,pk/f,x9/1,pe/p,x10/15,s10,x7/9,s3,x6/2,pj/f,x11/4,s3,x6/10,po/m,s11,x14/9,s14,x15/0,s6,pf/g,x6/4,s3,x7/14,pe/m,x13/4,s3,x3/8,pa/d,x12/1,s14,x5/9,pn/c,x7/15,pi/o,x6/1,s6,x4/2,pa/c,x0/1,ph/n,x4/2,pf/k,x15/12,pj/b,x4/11,s1,x8/14,s5,pm/n,x0/6,pg/e,x1/8,pm/h,x10/6,pn/p,x13/11,s1,x0/15,s4,po/i,x11/5,pf/k,x13/7,s9,x1/10,s1,pm/n,x8/6,s2,x0/11,s14,x7/1,s13,x10/0,pc/p,x14/2,s1,x6/1,po/m,x0/5,s2,x4/6,s12,x7/10,s4,x14/2,s4,x11/7,s10,x8/9,pl/a,x7/4,pb/e,s9,x5/14,s6,x4/3,po/c,x13/5,s8,x3/15,s11,x14/0,pl/j,x1/4,pb/f,x7/8,s2,x11/5,pc/l,x0/12,pi/d,x13/9,pm/k,x2/3,s2,x15/14,pi/e,x2/9,s8,x8/12,s6,x4/3,pl/g,x10/14,s9,x9/3,s15,pm/d,x4/7,s7,x6/3,s4,x15/11,pl/b,x13/12,s2,x1/7,ph/e,x11/14,pc/i,x13/12,s13,x10/1,s9,x8/5,pe/k,x4/11,s5,x1/10,s13,x4/2,s15,x13/9,s9,pl/i,x5/10,s8,x7/3,pf/a,x1/2,s15,x3/8,pe/c,x9/12,pn/o,x3/14,s7,x4/9,pc/f,x10/8,s15,x12/6,ph/m,x14/15,s15,x8/2,pn/e,x11/7,s1,x6/14,pi/f,x1/15,s4,pl/h,x11/9,pc/k,x14/10,s13,x5/3,s5,x2/8,pi/g,x14/7,s2,x8/10,s8,x14/3,pm/l,s5,x15/1,pb/h,s6,x12/2,pc/l,x13/8,s8,x15/9,pf/b,x7/5,s11,x8/0,s13,x9/6,pj/c,x5/14,ph/m,x10/7,s3,x1/4,s10,x12/5,s13,x13/1,pi/o,x8/6,s15,x1/0,s6,x10/6,pc/a,x0/5,pb/j,x14/13,pp/c,x3/1,pa/b,x9/4,pi/p,x2/5,s13,x13/3,s5,x9/0,s10,x2/14,s1,x1/5,s4,x9/13,s3,x14/12,pc/d,x8/11,s12,x14/1,po/e,x11/4,s6,x15/12,pa/b,x13/1,pp/j,s12,x4/12,s6,x8/5,pg/n,x7/6,s9,x4/5,s10,x0/7,s3,x4/10,s13,x13/12,pd/p,x9/15,pn/m,x14/6,pj/p,x8/9,pg/k,x0/3,pf/h,x10/4,s1,x0/2,s3,x1/3,po/g,x6/12,ph/f,x4/8,s14,x12/11,s3,x9/7,s12,x13/15,pb/p,x6/2,pi/f,x8/12,s8,x0/7,pc/g,s6,x5/13,s13,x12/2,s4,x0/9,s11,x8/14,s14,x7/9,s6,x13/12,pk/n,x10/14,s1,x15/9,s14,x11/12,pf/m,x4/1,pi/d,x8/13,pg/e,s4,x5/11,s8,x15/0,s12,x1/12,pa/i,x14/7,pk/n,x10/11,s2,x6/13,ph/f,x5/2,s11,x15/7,pc/i,x2/13,s12,x8/0,po/l,s12,x6/15,s10,x3/1,s14,ph/i,s6,x10/0,s2,x2/9,s10,x5/0,pf/p,x6/3,pk/a,x1/4,pd/o,x0/2,pl/h,x12/8,s10,x7/6,s10,x15/10,pk/g,x7/9,s11,x11/13,pp/o,s7,x9/7,s2,x3/12,s8,x4/10,pj/l,x13/14,s12,x12/0,s11,x5/2,s11,x9/0,s1,x2/10,pg/i,x5/7,pc/m,x1/8,po/i,x13/0,s4,x3/6,s10,x4/9,s5,x8/10,s5,x11/6,pm/n,x10/2,pe/p,x14/8,s3,x1/11,s13,x9/3,ph/k,x0/15,po/m,x8/7,ph/c,x0/5,s13,x7/9,s9,x10/0,s6,x6/13,s8,x9/14,s10,x12/15,s10,x14/1,s11,x5/12,s12,pb/j,x7/15,s6,x12/13,pe/i,x10/6,s12,x5/15,s9,x14/2,s8,x0/11,s8,x1/5,pp/m,x6/9,pi/n,s5,x0/13,pa/o,x3/1,pi/e,x4/6,s6,x2/13,pp/f,x3/4,s5,x14/13,s5,x5/8,s7,x12/9,s6,x5/8,pn/c,x10/12,s14,x6/14,pb/d,x4/2,pn/o,x9/5,pk/a,x12/10,s12,x2/4,pf/p,x8/7,s3,x9/14,s13,x12/13,s7,x10/2,pa/m,x5/11,s15,x12/15,s5,x6/0,s3,x7/1,s9,x5/4,ph/f,x1/2,pa/n,x14/12,pm/c,x10/8,s12,x7/12,s11,x14/4,pp/a,x10/0,pe/f,x7/2,s5,x13/10,pl/i,x6/3,pp/e,x4/9,s10,x14/5,s15,ph/d,x9/3,s2,x7/4,pk/f,x5/15,s9,x1/2,s3,x10/9,pg/n,x0/15,s15,pm/e,x9/8,s3,x12/10,po/d,x4/13,pb/h,x15/3,s6,x14/10,s7,x15/11,s7,x0/3,pj/d,x2/10,s7,pf/l,s9,x12/5,s1,x15/2,s6,x6/0,ph/o,x1/10,s3,x12/2,pd/c,x10/15,pf/j,x3/14,s15,x11/12,pn/h,x0/13,s12,x2/5,s1,x12/1,pp/i,s13,x15/10,s13,x8/13,s15,x1/10,s1,x6/0,s14,x9/5,s11,x4/8,s11,x13/5,ph/d,x15/8,pp/i,s13,x13/10,pk/c,x2/14,s6,x3/11,s9,x9/5,pl/d,x4/12,pc/k,x10/5,pb/i,x8/4,pm/k,x1/2,s4,x8/7,po/f,x3/6,pb/d,x7/9,pn/i,s2,x6/3,pp/k,x9/0,s9,x15/2,s3,x13/10,s6,x9/14,s4,x1/7,s11,x11/6,s1,x1/8,s8,x14/10,pa/f,x5/15,pi/m,x4/7,pa/d,x8/5,pi/l,x9/13,s12,x4/12,s1,x15/8,s1,x7/9,s8,pc/g,x15/4,s11,x7/9,s13,x5/2,s14,x13/3,s8,x1/9,pj/k,x11/6,pc/e,x2/12,s6,x7/11,s14,x0/13,s2,x3/11,s11,x7/10,s12,x1/9,po/k,s11,x12/10,pm/d,x15/0,s11,x11/2,pp/n,x15/12,s3,x10/9,s13,x5/15,s4,x1/14,s9,x15/5,s15,x9/11,pf/c,x4/2,s13,x3/8,s15,x2/7,s10,x6/15,s4,x4/7,s4,x13/0,pm/e,x14/4,pn/f,x8/5,pb/k,s7,x6/15,s8,x10/11,s1,x3/0,s7,x15/1,s10,x8/0,s12,x10/3,pd/p,x1/8,s10,x15/10,s13,x13/8,pb/n,x3/2,s8,pl/c,x5/10,pn/a,s3,x0/8,s3,x12/13,s13,x7/3,s15,x0/8,pe/d,x15/4,s11,x12/14,s2,x10/6,s15,x8/13,s7,x3/10,s4,x6/11,s4,x3/13,s4,x12/15,s7,x7/10,s5,x1/5,s2,x8/14,s11,x9/0,s3,x2/15,s11,x8/10,s1,x11/6,s6,x4/9,s10,x5/7,pb/j,x6/12,s13,x5/8,pn/g,x10/6,pe/d,x0/8,pp/n,x1/14,s12,x7/3,pe/o,x11/1,pd/f,x3/12,ph/a,x10/7,s5,x0/6,s6,x1/9,s1,x3/0,s11,x14/7,pb/i,x4/1,pd/n,x9/0,s4,pj/b,x10/15,s6,x5/14,s14,x6/11,s3,pc/p,x4/2,s3,x1/6,ph/j,x12/5,s8,x2/8,s9,x5/9,s6,pa/i,x6/10,pg/j,s3,x7/12,s11,x3/4,s8,x6/10,s8,x3/9,pm/l,x12/2,s14,x13/6,pp/k,s6,x9/14,s4,x7/5,pj/d,x8/14,s6,pp/b,x2/1,pi/o,x7/5,pp/k,x9/14,s9,x12/1,pl/d,x10/2,s3,pn/e,s11,x9/12,pm/d,x7/15,pc/i,x6/2,s5,x15/14,s9,x13/2,s14,x5/7,s10,x1/6,pf/a,x11/0,s11,x4/7,s12,x5/12,pe/o,x6/3,pp/a,x7/12,pb/l,x0/9,s6,x13/3,pm/c,x9/1,s14,x10/5,s7,x12/6,ph/i,x14/1,s15,po/b,x15/4,s5,x14/0,s8,x6/15,s3,x7/4,ph/m,s10,x9/5,s13,x6/3,pl/p,x10/14,s7,pa/e,x1/3,s14,x14/9,s11,x5/13,pk/h,s11,x9/14,s1,x3/4,s13,x9/11,pa/p,s5,x8/0,s3,x7/11,ph/n,x14/1,pp/j,x5/4,pk/f,x3/0,s5,pd/p,s12,x1/9,s12,x5/0,s14,x10/15,s15,x5/4,po/h,s2,x9/8,pa/g,x1/4,s1,x11/10,s4,x2/13,s7,pb/n,x14/7,pc/p,x3/0,s2,x12/2,s2,x7/15,pm/n,x10/8,pj/h,x0/14,s12,x13/3,pa/o,x11/8,s10,x5/13,ph/i,x1/12,s9,x13/8,s14,x14/11,s2,x6/13,s1,x1/8,s11,x14/4,s5,po/n,x2/12,pk/g,x10/14,s11,pi/j,x1/2,pd/h,x5/15,s2,x13/9,s1,x1/15,s3,x8/3,pn/g,x5/4,pa/f,x8/12,s9,x7/0,s14,x11/10,s14,x13/5,s5,pc/g,x11/6,s1,x15/8,po/e,x2/7,s1,x0/5,pd/c,x10/1,pi/b,x9/4,s11,x7/12,s14,x1/8,s4,x15/5,s3,x11/8,s6,x5/1,pg/p,x10/4,s5,pa/n,x3/11,pp/e,x1/4,s13,x12/6,po/a,s8,pk/i,x13/9,s2,x3/0,s9,x14/9,s13,x0/13,pl/d,x9/12,pb/i,x10/6,s4,x5/7,s3,x13/14,s9,x3/6,s6,x14/11,pj/g,x13/7,s2,x9/3,pn/b,x14/5,s8,x0/8,s1,x9/6,s7,x5/14,s14,x11/0,pi/h,x13/7,s15,x8/6,s10,x9/2,pp/g,x12/1,s11,x2/9,s7,x10/7,s9,x11/2,pe/h,x9/6,pc/m,x14/1,pe/o,x10/3,s14,x4/1,s15,x7/13,pb/g,x12/15,pd/l,s9,x11/0,po/i,x3/7,s1,x9/4,pp/m,x13/2,s12,x7/4,po/b,x15/12,pc/k,s10,po/e,s1,pd/g,s14,pc/e,x0/7,pm/p,s3,x10/13,s9,x12/14,s15,x6/0,s13,x9/3,pc/f,x10/14,s4,x2/11,pl/o,x12/14,pi/a,x10/4,s12,x0/13,pl/p,x14/3,pe/b,s14,x12/0,s4,pk/p,x4/10,s15,x11/14,s2,x7/6,s1,pl/n,x9/15,s12,x1/4,s6,pg/h,x9/14,s2,x10/15,pl/e,x13/14,pi/f,x6/1,pd/l,x13/5,s8,x7/8,s8,x14/9,po/a,x1/15,s14,x6/5,pl/d,s1,x12/10,s2,x14/15,pj/a,x13/7,s15
-        "#;
-        println!("answer: {}", run("abcdefghijklmnop", &input));
+    fn bad_move() {
+        let err = |s| match Day::parse(s) {
+            Err(Error::Parse(e)) => e,
+            _ => panic!("{:?} should not parse", s),
+        };
+        assert_eq!(err("s1,x3-4"), ParseError::new(1, 8, "", "`/`"));
+        assert_eq!(err("s1,x3/16"), ParseError::new(1, 7, "16", "a position below 16"));
+        assert_eq!(err("pa/q"), ParseError::new(1, 4, "q", "a program from a to p"));
+        assert_eq!(err("s17"), ParseError::new(1, 2, "17", "a spin of up to 16"));
+        assert_eq!(err("s1,d2"), ParseError::new(1, 4, "d2", "a spin, exchange or partner move"));
     }
 }
@@ -0,0 +1,44 @@
+use common::Rng;
+
+/// How many lengths to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub lengths: usize,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { lengths: 16 }
+    }
+}
+
+/// Lengths up to 256, the size of the list they twist
+pub fn lengths(rng: &mut Rng, size: &Size) -> Vec<usize> {
+    (0..size.lengths.max(1)).map(|_| rng.below(257) as usize).collect()
+}
+
+pub fn format(lengths: &[usize]) -> String {
+    lengths.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(",") + "\n"
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&lengths(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use {number_lengths, Day};
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(10);
+        for n in 1..20 {
+            let lengths = lengths(&mut rng, &Size { lengths: n });
+            let parsed = Day::parse(&format(&lengths)).unwrap();
+            assert_eq!(number_lengths(&parsed).unwrap(), lengths);
+            assert_eq!(Day::part2(&parsed).unwrap().len(), 32);
+        }
+    }
+}
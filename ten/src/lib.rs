@@ -1,14 +1,26 @@
 #![allow(dead_code)]
 
+extern crate common;
+
+use common::{Error, ParseError, Solution};
+
+pub mod gen;
+
 #[derive(Debug)]
-struct List {
+pub struct List {
     curr: usize,
     skip_size: usize,
     list: Vec<i64>,
 }
 
+impl Default for List {
+    fn default() -> List {
+        List::new()
+    }
+}
+
 impl List {
-    fn new() -> List {
+    pub fn new() -> List {
         List::with_list((0..256).collect::<Vec<_>>())
     }
 
@@ -57,8 +69,8 @@ impl List {
 }
 
 fn get_lengths(input: &str) -> Vec<usize> {
-    let mut lengths = input.chars()
-        .map(|c| c as u32 as usize)
+    let mut lengths = input.bytes()
+        .map(usize::from)
         .collect::<Vec<usize>>();
     lengths.extend_from_slice(&[17, 31, 73, 47, 23]);
     lengths
@@ -68,7 +80,7 @@ fn run_one(lengths: &[usize], list: &mut List) -> i64 {
     for len in lengths {
         list.step(*len).expect("Couldnt do step");
         list.curr += len + list.skip_size;
-        list.curr = list.curr % list.list.len();
+        list.curr %= list.list.len();
         list.skip_size += 1;
     }
     list.list[0] * list.list[1]
@@ -94,25 +106,74 @@ fn to_hex(num: i64) -> String {
     format!("{:02x}", num)
 }
 
-fn run(input: &str, list: &mut List) -> String {
+pub fn run(input: &str, list: &mut List) -> String {
     let lengths = get_lengths(input);
     for _ in 0..64 {
         run_one(&lengths, list);
     }
-    let dense = get_dense_hash(&list);
+    let dense = get_dense_hash(list);
     let ashex = dense.iter().map(|d| to_hex(*d)).collect::<Vec<_>>();
     ashex.join("")
 }
 
+// part one reads the input as a list of numbers rather than bytes
+fn number_lengths(input: &str) -> Result<Vec<usize>, ParseError> {
+    input.split(',')
+         .map(|s| s.trim())
+         .map(|s| match s.parse() {
+             Ok(n) if n <= 256 => Ok(n),
+             _ => Err(ParseError::at(input, s, "a length between 0 and 256")),
+         })
+         .collect()
+}
+
+// part two hashes the input a byte at a time, which only matches the puzzle for ASCII
+fn ascii(input: &str) -> Result<(), ParseError> {
+    match input.char_indices().find(|&(_, c)| !c.is_ascii()) {
+        Some((i, c)) => Err(ParseError::at(input, &input[i..i + c.len_utf8()], "an ASCII character")),
+        None => Ok(()),
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = String;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<String, Error> {
+        ascii(input)?;
+        Ok(input.trim().into())
+    }
+
+    fn part1(input: &String) -> Result<i64, Error> {
+        let lengths = number_lengths(input)?;
+        Ok(run_one(&lengths, &mut List::new()))
+    }
+
+    fn part2(input: &String) -> Result<String, Error> {
+        Ok(run(input, &mut List::new()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_input() {
-        let mut l = List::new();
-        let input = "199,0,255,136,174,254,227,16,51,85,1,2,22,17,7,192";
-        println!("answer is {}", run(input, &mut l));
+    fn single_round() {
+        let mut l = List::with_list(vec![0, 1, 2, 3, 4]);
+        assert_eq!(run_one(&[3, 4, 1, 5], &mut l), 12);
+        assert_eq!(Day::part1(&"3,4,1,5, 257".into()).unwrap_err(),
+                   Error::Parse(ParseError::new(1, 10, "257", "a length between 0 and 256")));
+    }
+
+    #[test]
+    fn not_ascii() {
+        assert_eq!(Day::parse("1,2\n3€").unwrap_err(),
+                   Error::Parse(ParseError::new(2, 2, "€", "an ASCII character")));
+        assert_eq!(run("€", &mut List::new()).len(), 32);
     }
 
     /*
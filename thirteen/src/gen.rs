@@ -0,0 +1,66 @@
+use common::Rng;
+
+/// How big a firewall to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub layers: usize,
+    /// The most empty layers between two scanners
+    pub max_gap: u32,
+    /// Ranges run from 2, since a scanner with range 1 catches every packet
+    pub max_range: u32,
+    /// A delay no bigger than this always gets through
+    pub max_delay: u32,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { layers: 43, max_gap: 2, max_range: 20, max_delay: 4_000_000 }
+    }
+}
+
+/// `(depth, range)` for each scanner, arranged so part 2 has an answer no bigger than
+/// `size.max_delay`
+pub fn layers(rng: &mut Rng, size: &Size) -> Vec<(u32, u32)> {
+    let delay = rng.below(u64::from(size.max_delay) + 1) as u32;
+    let max_range = u64::from(size.max_range.max(2));
+    let mut layers = vec![];
+    let mut depth = 0;
+    while layers.len() < size.layers {
+        // a handful of tries is plenty; if every range would catch the packet, leave the layer empty
+        for _ in 0..10 {
+            let range = rng.below(max_range - 1) as u32 + 2;
+            if !(delay + depth).is_multiple_of((range - 1) * 2) {
+                layers.push((depth, range));
+                break;
+            }
+        }
+        depth += rng.below(u64::from(size.max_gap) + 1) as u32 + 1;
+    }
+    layers
+}
+
+pub fn format(layers: &[(u32, u32)]) -> String {
+    layers.iter().map(|&(depth, range)| format!("{}: {}\n", depth, range)).collect()
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&layers(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(13);
+        for n in 1..30 {
+            let layers = layers(&mut rng, &Size { layers: n, max_gap: 3, max_range: 8, max_delay: 1000 });
+            let parsed = Day::parse(&format(&layers)).unwrap();
+            assert_eq!(parsed, layers);
+            assert!(Day::part2(&parsed).unwrap() <= 1000);
+        }
+    }
+}
@@ -1,39 +1,111 @@
+extern crate common;
+
+use common::{Error, Observer, ParseError, Silent, Solution};
+
+pub mod gen;
+
 fn num_steps(range: u32) -> u32 {
     (range - 1) * 2
 }
 
 fn position(delay: u32, depth: u32, range: u32) -> u32 {
     let num_steps = num_steps(range);
-    (delay + num_steps + depth) % num_steps
+    if num_steps == 0 {
+        // a scanner with a range of 1 never leaves the top
+        return 0;
+    }
+    ((u64::from(delay) + u64::from(depth)) % u64::from(num_steps)) as u32
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// every scanner is back where it started after this many picoseconds, or `None` if that's more
+// than a `u64` holds
+fn period(layer_input: &[(u32, u32)]) -> Option<u64> {
+    layer_input.iter()
+               .map(|&(_, range)| u64::from(num_steps(range)))
+               .try_fold(1u64, |lcm, steps| (lcm / gcd(lcm, steps)).checked_mul(steps))
 }
 
 fn is_zero(delay: u32, pair: &(u32, u32)) -> bool {
     position(delay, pair.0, pair.1) == 0
 }
 
-fn run(input: &str) -> u32 {
-    let layer_input = input.trim()
-                           .lines()
-                           .map(|s| s.trim())
-                           .filter(|s| !s.is_empty())
-                           .map(|s| s.split(": "))
-                           .map(|mut spl| (spl.next().expect("didn't get left side").parse::<u32>().expect("Couldn't parse left side"),
-                                           spl.next().expect("didn't get right side").parse::<u32>().expect("Couldn't parse right side")))
-                           .collect::<Vec<_>>();
-    let mut i = 0;
-    loop {
-        println!("with delay: {}", i);
-        let num = layer_input.iter().filter(|p| is_zero(i, p)).count();
-        if num == 0 {
-            break i;
+fn parse_layer(input: &str, line: &str) -> Result<(u32, u32), ParseError> {
+    let mut spl = line.splitn(2, ':');
+    let left = spl.next().unwrap().trim();
+    let right = spl.next()
+                   .ok_or_else(|| ParseError::at(input, &line[line.len()..], "`:`"))?
+                   .trim();
+    let depth = left.parse().map_err(|_| ParseError::at(input, left, "a depth"))?;
+    match right.parse() {
+        Ok(range) if range > 0 => Ok((depth, range)),
+        _ => Err(ParseError::at(input, right, "a range of at least 1")),
+    }
+}
+
+fn parse_layers(input: &str) -> Result<Vec<(u32, u32)>, ParseError> {
+    input.lines()
+         .map(|s| s.trim())
+         .filter(|s| !s.is_empty())
+         .map(|s| parse_layer(input, s))
+         .collect()
+}
+
+fn find_delay(layer_input: &[(u32, u32)]) -> Result<u32, Error> {
+    find_delay_with(layer_input, &mut Silent)
+}
+
+/// Like `find_delay`, showing `observer` each delay as it's tried. Only one period of the
+/// scanners is searched, since every delay after that repeats one before it.
+pub fn find_delay_with<O: Observer<u32>>(layer_input: &[(u32, u32)], observer: &mut O) -> Result<u32, Error> {
+    if let Some(&(depth, _)) = layer_input.iter().find(|&&(_, range)| range == 1) {
+        return Err(Error::NoAnswer(format!("the scanner at depth {} has a range of 1 and catches every packet",
+                                           depth)));
+    }
+    let end = period(layer_input).map_or(u64::from(u32::MAX) + 1, |p| p.min(u64::from(u32::MAX) + 1));
+    for i in 0..end {
+        let i = i as u32;
+        observer.observe(&i);
+        if !layer_input.iter().any(|p| is_zero(i, p)) {
+            return Ok(i);
         }
-        i += 1;
+    }
+    Err(Error::NoAnswer("no delay gets through without being caught".into()))
+}
+
+pub fn run(input: &str) -> Result<u32, Error> {
+    find_delay(&parse_layers(input)?)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<(u32, u32)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<(u32, u32)>, Error> {
+        Ok(parse_layers(input)?)
+    }
+
+    // the severity of leaving straight away
+    fn part1(layers: &Vec<(u32, u32)>) -> Result<u32, Error> {
+        Ok(layers.iter().filter(|p| is_zero(0, p)).map(|&(depth, range)| depth * range).sum())
+    }
+
+    fn part2(layers: &Vec<(u32, u32)>) -> Result<u32, Error> {
+        find_delay(layers)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::{Differential, Rng};
+
     #[test]
     fn it_works() {
         let input = r#"
@@ -42,57 +114,60 @@ mod tests {
             4: 4
             6: 4
         "#;
-        assert_eq!(run(&input), 10);
+        assert_eq!(run(input).unwrap(), 10);
+        assert!(run("0: 3\n1 2").is_err());
+        assert_eq!(Day::part1(&Day::parse(input).unwrap()).unwrap(), 24);
     }
 
     #[test]
-    fn test_answer() {
-        let input = r#"
-            0: 3
-            1: 2
-            2: 4
-            4: 6
-            6: 5
-            8: 8
-            10: 6
-            12: 4
-            14: 8
-            16: 6
-            18: 8
-            20: 8
-            22: 6
-            24: 8
-            26: 9
-            28: 12
-            30: 8
-            32: 14
-            34: 10
-            36: 12
-            38: 12
-            40: 10
-            42: 12
-            44: 12
-            46: 12
-            48: 12
-            50: 14
-            52: 12
-            54: 14
-            56: 12
-            60: 14
-            62: 12
-            64: 14
-            66: 14
-            68: 14
-            70: 14
-            72: 14
-            74: 14
-            78: 26
-            80: 18
-            82: 17
-            86: 18
-            88: 14
-            96: 18
-        "#;
-        println!("{}", run(&input));
+    fn bad_layer() {
+        let err = |s| match Day::parse(s) {
+            Err(Error::Parse(e)) => e,
+            _ => panic!("{:?} should not parse", s),
+        };
+        assert_eq!(err("0: 3\n1 2"), ParseError::new(2, 4, "", "`:`"));
+        assert_eq!(err("0: 3\n1: 0"), ParseError::new(2, 4, "0", "a range of at least 1"));
+        assert_eq!(err("x: 3"), ParseError::new(1, 1, "x", "a depth"));
+    }
+
+    // steps every scanner one picosecond at a time until the packet reaches it
+    fn simulate(delay: u32, layers: &[(u32, u32)]) -> Vec<(u32, u32)> {
+        layers.iter().cloned().filter(|&(depth, range)| {
+            let (mut pos, mut down) = (0, true);
+            for _ in 0..(delay + depth) {
+                if range == 1 {
+                    break;
+                }
+                if (down && pos == range - 1) || (!down && pos == 0) {
+                    down = !down;
+                }
+                pos = if down { pos + 1 } else { pos - 1 };
+            }
+            pos == 0
+        }).collect()
+    }
+
+    #[test]
+    fn position_matches_simulation() {
+        // ranges are generated from 0 so they can shrink, but start at 1
+        let ranged = |layers: &[(u32, u32)]| layers.iter().map(|&(d, r)| (d, r + 1)).collect::<Vec<_>>();
+        let layer = |rng: &mut Rng| (rng.below(20) as u32, rng.below(6) as u32);
+        Differential::new(200).run(|rng| (rng.below(50) as u32, (0..5).map(|_| layer(rng)).collect::<Vec<_>>()),
+                                   |&(delay, ref layers)| simulate(delay, &ranged(layers)),
+                                   |&(delay, ref layers)| ranged(layers).into_iter().filter(|p| is_zero(delay, p)).collect());
+    }
+
+    #[test]
+    fn range_one() {
+        assert_eq!(Day::part1(&Day::parse("2: 1").unwrap()).unwrap(), 2);
+        assert_eq!(run("0: 3\n2: 1"),
+                   Err(Error::NoAnswer("the scanner at depth 2 has a range of 1 and catches every packet".into())));
+    }
+
+    #[test]
+    fn always_caught() {
+        // every even delay is caught at depth 0 and every odd one at depth 1
+        assert_eq!(run("0: 2\n1: 2"), Err(Error::NoAnswer("no delay gets through without being caught".into())));
+        assert_eq!(period(&[(0, 2), (1, 3), (4, 4)]), Some(12));
     }
 }
@@ -0,0 +1,38 @@
+use common::Rng;
+
+/// How far out along the spiral to go
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub max_square: u64,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { max_square: 400_000 }
+    }
+}
+
+pub fn square(rng: &mut Rng, size: &Size) -> u64 {
+    rng.below(size.max_square.max(1)) + 1
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format!("{}\n", square(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(3);
+        for n in 1..30 {
+            let square = square(&mut rng, &Size { max_square: n });
+            assert!(square >= 1 && square <= n);
+            assert_eq!(Day::parse(&format!("{}\n", square)).unwrap(), square);
+        }
+    }
+}
@@ -37,12 +37,30 @@ level: 2
 
 */
 
+extern crate common;
+
+use common::{Error, Observer, ParseError, Silent, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+pub mod gen;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Cell {
     pos: (isize, isize),
     val: u64,
 }
 
+impl Cell {
+    pub fn pos(&self) -> (isize, isize) {
+        self.pos
+    }
+
+    pub fn val(&self) -> u64 {
+        self.val
+    }
+}
+
 impl ::std::fmt::Display for Cell {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{}: ({}, {})", self.val, self.pos.0, self.pos.1)
@@ -55,6 +73,14 @@ pub struct Grid {
     num: u64,
     max: usize,
     pub cells: Vec<Cell>,
+    // what's been written where, for looking up neighbours
+    index: Written,
+}
+
+impl Default for Grid {
+    fn default() -> Grid {
+        Grid::new()
+    }
 }
 
 impl Grid {
@@ -64,6 +90,7 @@ impl Grid {
             num: 1,
             max: 0,
             cells: vec![],
+            index: HashMap::new(),
         }
     }
 
@@ -75,30 +102,47 @@ impl Grid {
     }
 
     pub fn build(&mut self, up_to_level: Option<usize>, up_to_num: u64) {
-        self.mark_curr();
+        self.build_with(up_to_level, up_to_num, &mut Silent)
+    }
+
+    /// Like `build`, showing `observer` each cell as it's written. Stops at the last value that
+    /// fits in a `u64` if the next one would overflow.
+    pub fn build_with<O: Observer<Cell>>(&mut self, up_to_level: Option<usize>, up_to_num: u64,
+                                         observer: &mut O) {
+        self.mark_curr(observer);
 
         let mut lvl = 0;
         while self.under_level(lvl, up_to_level) && self.num < up_to_num {
-            self.mark_right(up_to_num);
+            if !self.mark_right(observer) {
+                return;
+            }
 
             let up = (lvl * 2) + 1;
             for _ in 0..up {
-                self.mark_up(up_to_num);
+                if !self.mark_up(observer) {
+                    return;
+                }
             }
 
             let left = (lvl * 2) + 2;
             for _ in 0..left {
-                self.mark_left(up_to_num);
+                if !self.mark_left(observer) {
+                    return;
+                }
             }
 
             let down = (lvl * 2) + 2;
             for _ in 0..down {
-                self.mark_down(up_to_num);
+                if !self.mark_down(observer) {
+                    return;
+                }
             }
 
             let right = (lvl * 2) + 2;
             for _ in 0..right {
-                self.mark_right(up_to_num);
+                if !self.mark_right(observer) {
+                    return;
+                }
             }
 
             lvl += 1;
@@ -113,130 +157,353 @@ impl Grid {
 
     pub fn get_distance(&self, num: usize) -> u64 {
         let cell = self.get_cell_with_val(num);
-        let abs_x = cell.pos.0.abs() as u64;
-        let abs_y = cell.pos.1.abs() as u64;
+        let abs_x = cell.pos.0.unsigned_abs() as u64;
+        let abs_y = cell.pos.1.unsigned_abs() as u64;
         abs_x + abs_y
     }
 
-    fn get_val_for_cell(&self, pos: (isize, isize)) -> Option<u64> {
-        // definitely could do this better
-        for cell in &self.cells {
-            if pos == cell.pos {
-                return Some(cell.val)
+    fn get_val(&self, pos: (isize, isize)) -> Option<u64> {
+        neighbour_sum(&self.index, pos, &NEIGHBOURS)
+    }
+
+    fn check_max(&self, num: isize) -> bool {
+        num.unsigned_abs() > self.max
+    }
+
+    fn mark_curr<O: Observer<Cell>>(&mut self, observer: &mut O) {
+        let cell = Cell {
+            pos: self.pos,
+            val: self.num,
+        };
+        observer.observe(&cell);
+        self.index.insert(cell.pos, cell.val);
+        self.cells.push(cell);
+    }
+
+    // moves to `pos` and writes its value there, or leaves everything as it was and returns false
+    // if that value overflows
+    fn mark_at<O: Observer<Cell>>(&mut self, pos: (isize, isize), observer: &mut O) -> bool {
+        match self.get_val(pos) {
+            Some(num) => {
+                self.pos = pos;
+                self.num = num;
+                self.mark_curr(observer);
+                true
             }
+            None => false,
         }
-        None
     }
 
-    fn get_val_for_left(&self, pos: (isize, isize)) -> Option<u64> {
-        self.get_val_for_cell((pos.0 - 1, pos.1))
+    fn mark_left<O: Observer<Cell>>(&mut self, observer: &mut O) -> bool {
+        let pos = (self.pos.0 - 1, self.pos.1);
+        self.mark_at(pos, observer)
     }
 
-    fn get_val_for_up(&self, pos: (isize, isize)) -> Option<u64> {
-        self.get_val_for_cell((pos.0, pos.1 + 1))
+    fn mark_up<O: Observer<Cell>>(&mut self, observer: &mut O) -> bool {
+        let pos = (self.pos.0, self.pos.1 + 1);
+        if !self.mark_at(pos, observer) {
+            return false;
+        }
+        if self.check_max(self.pos.1 + 1) {
+            self.max = (self.pos.1 + 1) as usize;
+        }
+        true
     }
 
-    fn get_val_for_right(&self, pos: (isize, isize)) -> Option<u64> {
-        self.get_val_for_cell((pos.0 + 1, pos.1))
+    fn mark_right<O: Observer<Cell>>(&mut self, observer: &mut O) -> bool {
+        let pos = (self.pos.0 + 1, self.pos.1);
+        if !self.mark_at(pos, observer) {
+            return false;
+        }
+        if self.check_max(self.pos.0 + 1) {
+            self.max = (self.pos.0 + 1) as usize;
+        }
+        true
     }
 
-    fn get_val_for_down(&self, pos: (isize, isize)) -> Option<u64> {
-        self.get_val_for_cell((pos.0, pos.1 - 1))
+    fn mark_down<O: Observer<Cell>>(&mut self, observer: &mut O) -> bool {
+        let pos = (self.pos.0, self.pos.1 - 1);
+        self.mark_at(pos, observer)
     }
+}
+
+// the first four are the squares that share a side
+const NEIGHBOURS: [(isize, isize); 8] = [(-1, 0), (0, 1), (1, 0), (0, -1), (-1, 1), (1, 1), (-1, -1), (1, -1)];
+
+// the sum of whatever's been written in the squares `around` pos, or `None` if it overflows
+fn neighbour_sum(written: &Written, pos: (isize, isize), around: &[(isize, isize)]) -> Option<u64> {
+    around.iter()
+          .filter_map(|&(dx, dy)| written.get(&(pos.0 + dx, pos.1 + dy)))
+          .try_fold(0u64, |sum, &v| sum.checked_add(v))
+}
+
+/// Every value written so far, by position
+pub type Written = HashMap<(isize, isize), u64>;
+
+/// Works out a square's value from its number, where it is and everything written so far.
+/// `None` ends the spiral.
+pub type ValueFn<'a> = Box<dyn FnMut(u64, (isize, isize), &Written) -> Option<u64> + 'a>;
+
+/// What gets written into each square of the spiral
+pub enum Rule<'a> {
+    /// The square's own number, as in part 1
+    Index,
+    /// The sum of the eight squares around it written so far, as in part 2
+    EightNeighbours,
+    /// The sum of the four squares sharing a side with it written so far
+    FourNeighbours,
+    Custom(ValueFn<'a>),
+}
+
+/// The squares of the spiral in the order they're written, worked out as they're asked for. Ends
+/// early if a value overflows.
+pub struct SpiralIter<'a> {
+    rule: Rule<'a>,
+    next: Option<u64>,
+    written: Written,
+}
 
-    fn get_val_for_left_up(&self, pos: (isize, isize)) -> Option<u64> {
-        self.get_val_for_cell((pos.0 - 1, pos.1 + 1))
+impl<'a> SpiralIter<'a> {
+    pub fn new(rule: Rule<'a>) -> SpiralIter<'a> {
+        SpiralIter {
+            rule,
+            next: Some(1),
+            written: HashMap::new(),
+        }
     }
+}
 
-    fn get_val_for_right_up(&self, pos: (isize, isize)) -> Option<u64> {
-        self.get_val_for_cell((pos.0 + 1, pos.1 + 1))
+impl<'a> Iterator for SpiralIter<'a> {
+    type Item = Cell;
+
+    fn next(&mut self) -> Option<Cell> {
+        let n = self.next?;
+        let pos = spiral_position(n);
+        let val = match self.rule {
+            Rule::Index => Some(n),
+            Rule::EightNeighbours | Rule::FourNeighbours if n == 1 => Some(1),
+            Rule::EightNeighbours => neighbour_sum(&self.written, pos, &NEIGHBOURS),
+            Rule::FourNeighbours => neighbour_sum(&self.written, pos, &NEIGHBOURS[..4]),
+            Rule::Custom(ref mut f) => f(n, pos, &self.written),
+        };
+        self.next = match val {
+            Some(_) => n.checked_add(1),
+            None => None,
+        };
+        let val = val?;
+        // the numbers are all there is to it, so there's no need to remember them
+        if !matches!(self.rule, Rule::Index) {
+            self.written.insert(pos, val);
+        }
+        Some(Cell { pos, val })
     }
+}
 
-    fn get_val_for_left_down(&self, pos: (isize, isize)) -> Option<u64> {
-        self.get_val_for_cell((pos.0 - 1, pos.1 - 1))
+/// The first square the stress test writes a value larger than `target` into
+pub fn first_larger(target: u64) -> Result<Cell, Error> {
+    SpiralIter::new(Rule::EightNeighbours)
+        .find(|c| c.val > target)
+        .ok_or_else(|| Error::NoAnswer(format!("the values overflowed before any was larger than {}", target)))
+}
+
+/// Where square `n` lands, straight from which ring it's on and how far round that ring it is
+pub fn spiral_position(n: u64) -> (isize, isize) {
+    assert!(n > 0, "the spiral starts at square 1");
+    if n == 1 {
+        return (0, 0);
+    }
+    // ring k holds the squares after (2k - 1)^2, up to and including (2k + 1)^2
+    let k = (n - 1).isqrt().div_ceil(2);
+    let inner = (2 * k - 1) * (2 * k - 1);
+    let (k, t) = (k as isize, (n - inner - 1) as isize);
+    match t / (2 * k) {
+        0 => (k, t - k + 1),
+        1 => (3 * k - t - 1, k),
+        2 => (-k, 5 * k - t - 1),
+        _ => (t - 7 * k + 1, -k),
     }
+}
 
-    fn get_val_for_right_down(&self, pos: (isize, isize)) -> Option<u64> {
-        self.get_val_for_cell((pos.0 + 1, pos.1 - 1))
+/// The square the spiral puts at `pos`, the other way round from `spiral_position`. `None` if
+/// it's so far out the square's number doesn't fit in a `u64`.
+pub fn spiral_index(pos: (isize, isize)) -> Option<u64> {
+    let (x, y) = pos;
+    let k = x.unsigned_abs().max(y.unsigned_abs()) as u64;
+    if k == 0 {
+        return Some(1);
     }
+    let side = k.checked_mul(2)? - 1;
+    let inner = side.checked_mul(side)?;
+    let (k, x, y) = (i128::from(k), x as i128, y as i128);
+    let t = if x == k && y > -k {
+        y + k - 1
+    } else if y == k {
+        3 * k - x - 1
+    } else if x == -k {
+        5 * k - y - 1
+    } else {
+        x + 7 * k - 1
+    };
+    inner.checked_add(t as u64 + 1)
+}
 
-    fn get_val(&self, max_val: u64) -> u64 {
-        let mut val = 0;
-        if let Some(left) = self.get_val_for_left(self.pos) {
-            val += left;
-        }
-        if let Some(up) = self.get_val_for_up(self.pos) {
-            val += up;
-        }
-        if let Some(right) = self.get_val_for_right(self.pos) {
-            val += right;
-        }
-        if let Some(down) = self.get_val_for_down(self.pos) {
-            val += down;
-        }
-        if let Some(left_up) = self.get_val_for_left_up(self.pos) {
-            val += left_up;
-        }
-        if let Some(right_up) = self.get_val_for_right_up(self.pos) {
-            val += right_up;
-        }
-        if let Some(left_down) = self.get_val_for_left_down(self.pos) {
-            val += left_down;
-        }
-        if let Some(right_down) = self.get_val_for_right_down(self.pos) {
-            val += right_down;
-        }
-        if val > max_val {
-            println!("val {} is over {}", val, max_val);
+/// How many steps square `n` is from square 1
+pub fn manhattan_distance(n: u64) -> u64 {
+    let (x, y) = spiral_position(n);
+    x.unsigned_abs() as u64 + y.unsigned_abs() as u64
+}
+
+/// Which squares to pick out when drawing the spiral
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Highlight {
+    Nothing,
+    Square((isize, isize)),
+    /// A square and a shortest way from it back to square 1, across to the middle column first
+    PathHome((isize, isize)),
+}
+
+impl Highlight {
+    fn picks(&self, pos: (isize, isize)) -> bool {
+        // from 0 out to `end`, whichever side of 0 that is
+        let between = |n: isize, end: isize| n == 0 || (n.signum() == end.signum() && n.abs() <= end.abs());
+        match *self {
+            Highlight::Nothing => false,
+            Highlight::Square(p) => p == pos,
+            Highlight::PathHome((x, y)) => (pos.1 == y && between(pos.0, x)) || (pos.0 == 0 && between(pos.1, y)),
         }
-        val
     }
+}
 
-    fn check_max(&self, num: isize) -> bool {
-        (num.abs() as usize) > self.max
+/// Draws part of the spiral as a table with the axes along the edges, like the one at the top of
+/// this file. Highlighted squares are marked with a `*`.
+pub struct Picture<'a> {
+    grid: Option<&'a Grid>,
+    corners: Option<((isize, isize), (isize, isize))>,
+    highlight: Highlight,
+}
+
+impl<'a> Picture<'a> {
+    /// Everything written into `grid`
+    pub fn of(grid: &'a Grid) -> Picture<'a> {
+        let xs = grid.cells.iter().map(|c| c.pos.0);
+        let ys = grid.cells.iter().map(|c| c.pos.1);
+        let corners = match (xs.clone().min(), ys.clone().min(), xs.max(), ys.max()) {
+            (Some(x0), Some(y0), Some(x1), Some(y1)) => Some(((x0, y0), (x1, y1))),
+            _ => None,
+        };
+        Picture { grid: Some(grid), corners, highlight: Highlight::Nothing }
     }
 
-    fn mark_curr(&mut self) {
-        self.cells.push(Cell {
-            pos: self.pos,
-            val: self.num,
-        })
+    /// The square numbers between two opposite corners, without building anything
+    pub fn squares(from: (isize, isize), to: (isize, isize)) -> Picture<'static> {
+        Picture { grid: None, corners: None, highlight: Highlight::Nothing }.window(from, to)
     }
 
-    fn mark_left(&mut self, max_val: u64) {
-        self.pos = (self.pos.0 - 1, self.pos.1);
-        self.num = self.get_val(max_val);
-        self.mark_curr();
+    /// Only draws between two opposite corners
+    pub fn window(mut self, from: (isize, isize), to: (isize, isize)) -> Picture<'a> {
+        self.corners = Some(((from.0.min(to.0), from.1.min(to.1)), (from.0.max(to.0), from.1.max(to.1))));
+        self
     }
 
-    fn mark_up(&mut self, max_val: u64) {
-        self.pos = (self.pos.0, self.pos.1 + 1);
-        self.num = self.get_val(max_val);
-        if self.check_max(self.pos.1 + 1) {
-            self.max = (self.pos.1 + 1) as usize;
+    pub fn highlight(mut self, highlight: Highlight) -> Picture<'a> {
+        self.highlight = highlight;
+        self
+    }
+
+    fn label(&self, pos: (isize, isize)) -> String {
+        let val = match self.grid {
+            Some(grid) => grid.index.get(&pos).cloned(),
+            None => spiral_index(pos),
+        };
+        val.map_or_else(|| ".".into(), |v| v.to_string())
+    }
+}
+
+impl<'a> fmt::Display for Picture<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ((x0, y0), (x1, y1)) = match self.corners {
+            Some(corners) => corners,
+            None => return Ok(()),
+        };
+        let rows = (y0..=y1).rev()
+                            .map(|y| (y, (x0..=x1).map(|x| (self.highlight.picks((x, y)), self.label((x, y)))).collect()))
+                            .collect::<Vec<(isize, Vec<_>)>>();
+        let width = (x0..=x1).map(|x| x.to_string().len())
+                             .chain(rows.iter().flat_map(|r| r.1.iter().map(|c| c.1.len())))
+                             .max()
+                             .unwrap();
+        let margin = y0.to_string().len().max(y1.to_string().len());
+        write!(f, "{:1$}", "", margin + 1)?;
+        for x in x0..=x1 {
+            write!(f, " {:>1$}", x, width)?;
         }
-        self.mark_curr();
+        writeln!(f, "\n{:2$}{}", "", "-".repeat((x1 - x0 + 1) as usize * (width + 1)), margin + 1)?;
+        for (y, row) in rows {
+            write!(f, "{:>1$}|", y, margin)?;
+            for (picked, label) in row {
+                write!(f, "{:>1$}", if picked { format!("*{}", label) } else { label }, width + 1)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Picture::of(self))
     }
+}
 
-    fn mark_right(&mut self, max_val: u64) {
-        self.pos = (self.pos.0 + 1, self.pos.1);
-        self.num = self.get_val(max_val);
-        if self.check_max(self.pos.0 + 1) {
-            self.max = (self.pos.0 + 1) as usize;
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = u64;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<u64, Error> {
+        let num = input.trim();
+        match num.parse() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(ParseError::at(input, num, "a square number, counting from 1").into()),
         }
-        self.mark_curr();
     }
 
-    fn mark_down(&mut self, max_val: u64) {
-        self.pos = (self.pos.0, self.pos.1 - 1);
-        self.num = self.get_val(max_val);
-        self.mark_curr();
+    fn part1(num: &u64) -> Result<u64, Error> {
+        Ok(manhattan_distance(*num))
+    }
+
+    fn part2(num: &u64) -> Result<u64, Error> {
+        Ok(first_larger(*num)?.val)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::{Differential, Rng};
+
+    // walk the spiral a square at a time, the same way `Grid::build` does, to find where `num` goes
+    fn walk_to(num: u64) -> (isize, isize) {
+        let mut pos = (0, 0);
+        let mut square = 1;
+        let mut lvl = 0;
+        loop {
+            let side = (lvl * 2) + 2;
+            let legs = [((1, 0), 1), ((0, 1), side - 1), ((-1, 0), side), ((0, -1), side), ((1, 0), side)];
+            for &((dx, dy), steps) in &legs {
+                for _ in 0..steps {
+                    if square == num {
+                        return pos;
+                    }
+                    pos = (pos.0 + dx, pos.1 + dy);
+                    square += 1;
+                }
+            }
+            lvl += 1;
+        }
+    }
 
     #[test]
     fn basic_test() {
@@ -348,7 +615,145 @@ mod tests {
 
         assert_eq!(should_be, g.cells);
 
+        let mut seen = vec![];
+        let mut g = Grid::new();
+        g.build_with(Some(2), 932, &mut |c: &Cell| seen.push(c.clone()));
+        assert_eq!(should_be, seen);
+
         let mut g = Grid::new();
         g.build(None, 347992);
     }
+
+    #[test]
+    fn walk_matches_grid() {
+        let mut grid = Grid::new();
+        grid.build(Some(8), u64::MAX);
+        let cells = grid.cells.len() as u64;
+        Differential::new(200).run(|rng| rng.below(cells),
+                                   |&n| grid.cells[n as usize].pos,
+                                   |&n| walk_to(n + 1));
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(spiral_position(1), (0, 0));
+        assert_eq!(spiral_position(8), (0, -1));
+        assert_eq!(spiral_position(25), (2, -2));
+        assert_eq!(spiral_position(37), (-3, 3));
+        assert_eq!(spiral_position(43), (-3, -3));
+        let k = 1 << 31;
+        assert_eq!(spiral_position((2 * k as u64 - 1).pow(2)), (k - 1, 1 - k));
+        assert_eq!(spiral_position(u64::MAX), (2 - k, k));
+        assert_eq!(manhattan_distance(u64::MAX), (1 << 32) - 2);
+        Differential::new(300).run(|rng| rng.below(5000) + 1, |&n| walk_to(n), |&n| spiral_position(n));
+    }
+
+    #[test]
+    fn indices() {
+        assert_eq!(spiral_index((0, 0)), Some(1));
+        assert_eq!(spiral_index((-1, 1)), Some(5));
+        assert_eq!(spiral_index((2, -2)), Some(25));
+        assert_eq!(spiral_index((3, -2)), Some(26));
+        assert_eq!(spiral_index((-3, 3)), Some(37));
+        let k = 1 << 31;
+        assert_eq!(spiral_index((2 - k, k)), Some(u64::MAX));
+        assert_eq!(spiral_index((1 - k, k)), None);
+        assert_eq!(spiral_index((isize::MIN, isize::MAX)), None);
+
+        let mut rng = Rng::new(17);
+        for _ in 0..1000 {
+            let n = rng.next_u64().max(1);
+            assert_eq!(spiral_index(spiral_position(n)), Some(n));
+            let pos = (rng.between(-1000, 1000) as isize, rng.between(-1000, 1000) as isize);
+            assert_eq!(spiral_position(spiral_index(pos).unwrap()), pos);
+        }
+    }
+
+    #[test]
+    fn stress_test() {
+        assert_eq!(super::first_larger(0).unwrap(), Cell { pos: (0, 0), val: 1 });
+        assert_eq!(super::first_larger(1).unwrap(), Cell { pos: (1, 1), val: 2 });
+        assert_eq!(super::first_larger(747).unwrap(), Cell { pos: (0, -2), val: 806 });
+        match super::first_larger(u64::MAX) {
+            Err(Error::NoAnswer(e)) => assert!(e.contains("overflowed"), "{}", e),
+            r => panic!("{:?} should have overflowed", r),
+        }
+
+        let mut grid = Grid::new();
+        grid.build(Some(4), u64::MAX);
+        Differential::new(200).run(|rng| rng.below(grid.cells.last().unwrap().val),
+                                   |&n| grid.cells.iter().find(|c| c.val > n).cloned(),
+                                   |&n| super::first_larger(n).ok());
+    }
+
+    #[test]
+    fn lazily() {
+        let indices = SpiralIter::new(Rule::Index).take(100).collect::<Vec<_>>();
+        assert!(indices.iter().all(|c| c.pos == spiral_position(c.val)));
+
+        let mut grid = Grid::new();
+        grid.build(Some(3), u64::MAX);
+        assert_eq!(SpiralIter::new(Rule::EightNeighbours).take(grid.cells.len()).collect::<Vec<_>>(), grid.cells);
+        assert_eq!(SpiralIter::new(Rule::EightNeighbours).count(), 478);
+
+        // the eager build stops where the iterator does rather than overflowing
+        let mut grid = Grid::new();
+        grid.build(None, u64::MAX);
+        assert_eq!(grid.cells.len(), 478);
+        assert_eq!(grid.cells.last(), SpiralIter::new(Rule::EightNeighbours).last().as_ref());
+
+        let four = SpiralIter::new(Rule::FourNeighbours).map(|c| c.val).take(9).collect::<Vec<_>>();
+        assert_eq!(four, vec![1, 1, 1, 2, 2, 3, 3, 4, 5]);
+
+        // how many squares around each one were already written
+        let crowded = Rule::Custom(Box::new(|n, pos, written: &Written| {
+            if n > 25 {
+                return None;
+            }
+            Some(NEIGHBOURS.iter().filter(|&&(dx, dy)| written.contains_key(&(pos.0 + dx, pos.1 + dy))).count() as u64)
+        }));
+        let crowded = SpiralIter::new(crowded).collect::<Vec<_>>();
+        assert_eq!(crowded.len(), 25);
+        assert_eq!(crowded.iter().map(|c| c.val).take(10).collect::<Vec<_>>(), vec![0, 1, 2, 3, 2, 3, 2, 4, 3, 2]);
+    }
+
+    #[test]
+    fn pictures() {
+        assert_eq!(Picture::squares((3, 1), (-1, -1)).highlight(Highlight::Square((0, 0))).to_string(),
+                   "    -1  0  1  2  3\n   ---------------\n 1|  5  4  3 12 29\n 0|  6 *1  2 11 28\n-1|  7  8  9 10 27\n");
+
+        let mut grid = Grid::new();
+        grid.build(Some(2), 932);
+        let path = Picture::of(&grid).highlight(Highlight::PathHome((2, -2))).to_string();
+        assert_eq!(path.lines().collect::<Vec<_>>(),
+                   vec!["     -2  -1   0   1   2",
+                        "   --------------------",
+                        " 2| 147 142 133 122  59",
+                        " 1| 304   5   4   2  57",
+                        " 0| 330  10  *1   1  54",
+                        "-1| 351  11 *23  25  26",
+                        "-2| 362 747*806*880*931"]);
+        assert_eq!(grid.to_string(), Picture::of(&grid).to_string());
+        assert_eq!(Picture::squares((-1, -1), (1, 1)).highlight(Highlight::PathHome((0, 0))).to_string(),
+                   "    -1  0  1\n   ---------\n 1|  5  4  3\n 0|  6 *1  2\n-1|  7  8  9\n");
+        assert_eq!(Picture::squares((-1, -1), (1, 1)).highlight(Highlight::PathHome((-1, 1))).to_string(),
+                   "    -1  0  1\n   ---------\n 1| *5 *4  3\n 0|  6 *1  2\n-1|  7  8  9\n");
+        assert_eq!(Picture::of(&grid).window((2, 2), (3, 3)).to_string(), "    2  3\n  ------\n3|  .  .\n2| 59  .\n");
+        assert_eq!(Grid::new().to_string(), "");
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(Day::part1(&1).unwrap(), 0);
+        assert_eq!(Day::part1(&12).unwrap(), 3);
+        assert_eq!(Day::part1(&23).unwrap(), 2);
+        assert_eq!(Day::part1(&1024).unwrap(), 31);
+    }
+
+    #[test]
+    fn first_larger() {
+        assert_eq!(Day::part2(&Day::parse("747").unwrap()).unwrap(), 806);
+        assert_eq!(Day::parse(" 0\n").unwrap_err(),
+                   Error::Parse(ParseError::new(1, 2, "0", "a square number, counting from 1")));
+    }
 }
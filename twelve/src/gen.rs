@@ -0,0 +1,102 @@
+use common::Rng;
+
+/// How many programs to make, and how connected they are
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub programs: usize,
+    /// Extra pipes per program on top of the one joining it to an earlier program
+    pub max_pipes: usize,
+    /// One in this many programs starts a new group instead of joining an earlier one
+    pub new_group_odds: u64,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { programs: 2000, max_pipes: 3, new_group_odds: 10 }
+    }
+}
+
+fn connect(neighbors: &mut [Vec<i64>], a: usize, b: usize) {
+    if !neighbors[a].contains(&(b as i64)) {
+        neighbors[a].push(b as i64);
+    }
+    if !neighbors[b].contains(&(a as i64)) {
+        neighbors[b].push(a as i64);
+    }
+}
+
+/// Who each program can talk to. Pipes go both ways, and a program on its own pipes to itself.
+pub fn neighbors(rng: &mut Rng, size: &Size) -> Vec<Vec<i64>> {
+    let programs = size.programs.max(1);
+    let mut neighbors = vec![vec![]; programs];
+    let mut group_start = 0;
+    for p in 1..programs {
+        if rng.below(size.new_group_odds.max(1)) == 0 {
+            group_start = p;
+            continue;
+        }
+        let earlier = group_start + rng.below((p - group_start) as u64) as usize;
+        connect(&mut neighbors, p, earlier);
+        for _ in 0..rng.below(size.max_pipes as u64 + 1) {
+            let other = group_start + rng.below((p - group_start) as u64) as usize;
+            connect(&mut neighbors, p, other);
+        }
+    }
+    for (p, n) in neighbors.iter_mut().enumerate() {
+        if n.is_empty() {
+            n.push(p as i64);
+        }
+        n.sort();
+    }
+    neighbors
+}
+
+pub fn format(neighbors: &[Vec<i64>]) -> String {
+    neighbors.iter()
+             .enumerate()
+             .map(|(p, n)| format!("{} <-> {}\n", p, n.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ")))
+             .collect()
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&neighbors(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    // labels each program with the lowest program it can reach
+    fn flood(neighbors: &[Vec<i64>]) -> Vec<usize> {
+        let mut group = (0..neighbors.len()).collect::<Vec<_>>();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (p, n) in neighbors.iter().enumerate() {
+                for &n in n {
+                    let lowest = group[p].min(group[n as usize]);
+                    if group[p] != lowest || group[n as usize] != lowest {
+                        group[p] = lowest;
+                        group[n as usize] = lowest;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        group
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(12);
+        for n in 1..30 {
+            let neighbors = neighbors(&mut rng, &Size { programs: n, max_pipes: 2, new_group_odds: 4 });
+            let groups = flood(&neighbors);
+            let graph = Day::parse(&format(&neighbors)).unwrap();
+            assert_eq!(Day::part1(&graph).unwrap(), groups.iter().filter(|&&g| g == 0).count());
+            assert_eq!(Day::part2(&graph).unwrap(), (0..n).filter(|&p| groups[p] == p).count());
+        }
+    }
+}
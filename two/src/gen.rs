@@ -0,0 +1,66 @@
+use common::Rng;
+
+/// How big a spreadsheet to make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub rows: usize,
+    /// At least 2, so every row can hold the pair that divides evenly
+    pub columns: usize,
+    /// Raised to 8 times the columns if it's less, so there are enough values to go round
+    pub max_value: i64,
+}
+
+impl Default for Size {
+    fn default() -> Size {
+        Size { rows: 16, columns: 16, max_value: 6000 }
+    }
+}
+
+// plants one evenly dividing pair in each row, as the puzzle promises, and fills the rest with
+// values from the top half of the range that neither divide each other nor the pair
+pub fn rows(rng: &mut Rng, size: &Size) -> Vec<Vec<i64>> {
+    let columns = size.columns.max(2);
+    // leaves the top half room for every filler
+    let max_value = size.max_value.max(8 * columns as i64);
+    (0..size.rows).map(|_| {
+        let small = rng.between(2, max_value / 2);
+        let large = small * rng.between(2, max_value / small);
+        let mut row = vec![small, large];
+        while row.len() < columns {
+            let n = rng.between(max_value / 2 + 1, max_value);
+            if n % small != 0 && !row.contains(&n) {
+                row.push(n);
+            }
+        }
+        rng.shuffle(&mut row);
+        row
+    }).collect()
+}
+
+pub fn format(rows: &[Vec<i64>]) -> String {
+    rows.iter()
+        .map(|r| r.iter().map(|n| n.to_string()).collect::<Vec<_>>().join("\t") + "\n")
+        .collect()
+}
+
+pub fn input(rng: &mut Rng, size: &Size) -> String {
+    format(&rows(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use Day;
+
+    #[test]
+    fn round_trips() {
+        let mut rng = Rng::new(2);
+        for n in 1..20 {
+            let rows = rows(&mut rng, &Size { rows: n, columns: n, max_value: 50 * n as i64 });
+            let parsed = Day::parse(&format(&rows)).unwrap();
+            assert_eq!(parsed, rows);
+            assert!(Day::part2(&parsed).is_ok());
+        }
+    }
+}
@@ -1,62 +1,284 @@
-extern crate itertools;
-
-use itertools::Itertools;
-use std::io::{self, BufRead};
-
-fn prepare_row(row: io::Result<String>) -> Vec<u64> {
-    row.expect("Did not get row")
-        .split_whitespace()
-        .map(|s| s.trim())
-        .map(|s| s.parse().expect("Could not parse number successfully"))
-        .collect::<Vec<_>>()
-}
-
-fn get_divisible(row: Vec<u64>) -> (u64, u64) {
-    for nums in row.into_iter().combinations(2) {
-        let l = nums[0];
-        let r = nums[1];
-        if l > r {
-            if l % r == 0 {
-                return (l, r);
-            }
-        } else if r > l {
-            if r % l == 0 {
-                return (r, l);
+extern crate common;
+
+use common::{Error, ParseError, Solution};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::iter;
+
+pub mod gen;
+
+/// What separates the cells of a row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Delimiter {
+    /// Any run of spaces or tabs, as in the puzzle
+    Whitespace,
+    Char(char),
+}
+
+/// How a spreadsheet is laid out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Format {
+    pub delimiter: Delimiter,
+    /// Whether the first row holds column names rather than values
+    pub header: bool,
+}
+
+impl Format {
+    pub fn csv() -> Format {
+        Format { delimiter: Delimiter::Char(','), header: true }
+    }
+
+    pub fn tsv() -> Format {
+        Format { delimiter: Delimiter::Char('\t'), header: true }
+    }
+}
+
+impl Default for Format {
+    fn default() -> Format {
+        Format { delimiter: Delimiter::Whitespace, header: false }
+    }
+}
+
+/// Reads every row with a value in it. Blank cells are skipped, so rows can be different lengths.
+pub fn read_spreadsheet(input: &str, format: &Format) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut lines = input.lines().filter(|line| !line.trim().is_empty());
+    if format.header {
+        lines.next();
+    }
+    let mut rows = vec![];
+    for line in lines {
+        let cells = match format.delimiter {
+            Delimiter::Whitespace => line.split_whitespace().collect::<Vec<_>>(),
+            Delimiter::Char(c) => line.split(c).map(|cell| cell.trim()).filter(|cell| !cell.is_empty()).collect(),
+        };
+        let row = cells.into_iter()
+                       .map(|cell| cell.parse().map_err(|_| ParseError::at(input, cell, "a number")))
+                       .collect::<Result<Vec<_>, _>>()?;
+        if !row.is_empty() {
+            rows.push(row);
+        }
+    }
+    Ok(rows)
+}
+
+fn get_min_max(row: &[i64]) -> Option<(i64, i64)> {
+    Some((*row.iter().min()?, *row.iter().max()?))
+}
+
+fn calculate_diff(index: usize, min_max: (i64, i64)) -> Result<i64, Error> {
+    min_max.1.checked_sub(min_max.0).ok_or_else(|| {
+        Error::NoAnswer(format!("row {} overflows taking {} from {}", index, min_max.0, min_max.1))
+    })
+}
+
+fn empty_row(index: usize) -> Error {
+    Error::NoAnswer(format!("row {} is empty", index))
+}
+
+// adds what row `index` comes to onto the checksum so far
+fn add_row(sum: i64, index: usize, contribution: i64) -> Result<i64, Error> {
+    sum.checked_add(contribution)
+       .ok_or_else(|| Error::NoAnswer(format!("the checksum overflows at row {}", index)))
+}
+
+// up to `limit` of the (dividend, divisor)s from two different places in the row that divide
+// evenly, the dividend being the larger of the two ignoring sign; zero doesn't divide anything,
+// and two copies of the same value don't count, since anything divides itself. Once the sizes
+// are sorted each one only needs trying against the ones above it, either by stepping through
+// its multiples or by checking what's left, whichever is fewer.
+fn even_divisions(row: &[i64], limit: usize) -> Vec<(i64, i64)> {
+    let mut sizes: BTreeMap<u64, Vec<i64>> = BTreeMap::new();
+    for &v in row.iter().filter(|&&v| v != 0) {
+        sizes.entry(v.unsigned_abs()).or_default().push(v);
+    }
+    let distinct = sizes.keys().cloned().collect::<Vec<_>>();
+    let max = match distinct.last() {
+        Some(&max) => max,
+        None => return vec![],
+    };
+    let mut pairs = vec![];
+    for (i, &divisor) in distinct.iter().enumerate() {
+        let here = &sizes[&divisor];
+        let above = &distinct[i + 1..];
+        let dividends: Box<dyn Iterator<Item = u64>> = if max / divisor - 1 < above.len() as u64 {
+            Box::new((2..=max / divisor).map(|k| k * divisor).filter(|m| above.binary_search(m).is_ok()))
+        } else {
+            Box::new(above.iter().cloned().filter(|v| v % divisor == 0))
+        };
+        // the same size only divides evenly as v and -v, whichever comes first being the dividend,
+        // so each value pairs with however many of the other sign come after it
+        let mut after = vec![0; here.len()];
+        let (mut pos, mut neg) = (0, 0);
+        for (j, &v) in here.iter().enumerate().rev() {
+            after[j] = if v > 0 { neg } else { pos };
+            if v > 0 { pos += 1 } else { neg += 1 }
+        }
+        let first = here[0];
+        let second = here.iter().cloned().find(|&v| v != first).unwrap_or(first);
+        let same = here.iter().zip(after).flat_map(move |(&l, n)| {
+            iter::repeat_n((l, if l == first { second } else { first }), n)
+        });
+        let larger = dividends.flat_map(|d| sizes[&d].iter().flat_map(|&l| here.iter().map(move |&r| (l, r))));
+        for pair in same.chain(larger) {
+            if pairs.len() == limit {
+                return pairs;
             }
-        } else { // l == r, l / r == 1
-            return (l, r);
+            pairs.push(pair);
         }
     }
+    pairs
+}
+
+/// The two values in a row where one divides the other evenly
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pair {
+    /// Which row of the spreadsheet, counting from 0
+    pub row: usize,
+    pub dividend: i64,
+    pub divisor: i64,
+}
+
+impl Pair {
+    pub fn quotient(&self) -> Result<i64, Error> {
+        self.dividend.checked_div(self.divisor).ok_or_else(|| {
+            Error::NoAnswer(format!("row {} overflows dividing {} by {}", self.row, self.dividend, self.divisor))
+        })
+    }
+}
 
-    panic!("COULD NOT FIND TWO NUMBERS!");
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.dividend, self.divisor)
+    }
 }
 
-fn get_min_max(row: Vec<u64>) -> (u64, u64) {
-    let min = row.iter().min();
-    let max = row.iter().max();
-    (*min.expect("Could not get minimum value"), *max.expect("Could not get maximum value"))
+/// Finds the only two values in row `index` where one divides the other evenly. Two copies of the
+/// same value are never a pair.
+pub fn find_divisible(index: usize, row: &[i64]) -> Result<Pair, Error> {
+    // a second pair is enough to know it's no good
+    let pairs = even_divisions(row, 2).into_iter()
+                                   .map(|(dividend, divisor)| Pair { row: index, dividend, divisor })
+                                   .collect::<Vec<_>>();
+    match pairs.len() {
+        1 => Ok(pairs[0]),
+        0 => Err(Error::NoAnswer(format!("row {} has no two values that divide evenly", index))),
+        _ => {
+            let pairs = pairs.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+            Err(Error::NoAnswer(format!("row {} has more than one pair that divides evenly: {}",
+                                        index, pairs.join(", "))))
+        },
+    }
 }
 
-fn calculate_div(l_r: (u64, u64)) -> u64 {
-    l_r.0 / l_r.1
+/// The pair picked out of each row for the division checksum
+pub fn find_pairs(rows: &[Vec<i64>]) -> Result<Vec<Pair>, Error> {
+    rows.iter().enumerate().map(|(i, row)| find_divisible(i, row)).collect()
 }
 
-fn calculate_diff(min_max: (u64, u64)) -> u64 {
-    min_max.1 - min_max.0
+/// How each row of the spreadsheet is boiled down before the rows are added up
+#[derive(Clone, Copy)]
+pub enum Checksum<'a> {
+    /// The largest value less the smallest, as in part 1
+    Difference,
+    /// The two values that divide evenly, divided, as in part 2
+    Division,
+    Custom(&'a dyn Fn(&[i64]) -> i64),
 }
 
-pub fn calculate_checksum(input: &str) -> u64 {
-    io::Cursor::new(input.trim())
-            .lines()
-            .map(prepare_row)
-            .map(get_divisible)
-            .map(calculate_div)
-            .sum()
+impl<'a> Checksum<'a> {
+    /// Boils down row `index`
+    pub fn row(&self, index: usize, row: &[i64]) -> Result<i64, Error> {
+        match *self {
+            Checksum::Difference => calculate_diff(index, get_min_max(row).ok_or_else(|| empty_row(index))?),
+            Checksum::Division => find_divisible(index, row)?.quotient(),
+            Checksum::Custom(f) => Ok(f(row)),
+        }
+    }
+}
+
+pub fn checksum(rows: &[Vec<i64>], how: Checksum) -> Result<i64, Error> {
+    rows.iter().enumerate().try_fold(0, |sum, (i, row)| add_row(sum, i, how.row(i, row)?))
+}
+
+pub fn calculate_checksum(input: &str, how: Checksum) -> Result<i64, Error> {
+    checksum(&read_spreadsheet(input, &Format::default())?, how)
+}
+
+/// What one row of the spreadsheet comes to
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowReport {
+    pub row: usize,
+    pub min: i64,
+    pub max: i64,
+    /// Or why there isn't one
+    pub pair: Result<Pair, Error>,
+    /// What the row adds to the checksum, or why it can't be worked out
+    pub contribution: Result<i64, Error>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub rows: Vec<RowReport>,
+    /// The first row that couldn't be worked out fails the total, not the rest of the report
+    pub total: Result<i64, Error>,
+}
+
+/// Breaks the checksum down row by row. Only an empty row, which has no min or max to show, fails
+/// the whole report.
+pub fn report(rows: &[Vec<i64>], how: Checksum) -> Result<Report, Error> {
+    let rows = rows.iter()
+                   .enumerate()
+                   .map(|(i, row)| {
+                       let (min, max) = get_min_max(row).ok_or_else(|| empty_row(i))?;
+                       let pair = find_divisible(i, row);
+                       let contribution = match how {
+                           Checksum::Division => pair.clone().and_then(|p| p.quotient()),
+                           _ => how.row(i, row),
+                       };
+                       Ok(RowReport { row: i, min, max, pair, contribution })
+                   })
+                   .collect::<Result<Vec<_>, Error>>()?;
+    let total = rows.iter().try_fold(0, |sum, r| add_row(sum, r.row, r.contribution.clone()?));
+    Ok(Report { rows, total })
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "row  {:>12} {:>12}  {:>16}  {:>12}", "min", "max", "pair", "checksum")?;
+        for row in &self.rows {
+            let pair = row.pair.as_ref().map(|p| p.to_string()).unwrap_or_else(|_| "-".into());
+            let contribution = row.contribution.as_ref().map(|c| c.to_string()).unwrap_or_else(|_| "-".into());
+            writeln!(f, "{:3}  {:12} {:12}  {:>16}  {:>12}", row.row, row.min, row.max, pair, contribution)?;
+        }
+        let total = self.total.as_ref().map(|t| t.to_string()).unwrap_or_else(|_| "-".into());
+        writeln!(f, "total{:>57}", total)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+        Ok(read_spreadsheet(input, &Format::default())?)
+    }
+
+    fn part1(rows: &Vec<Vec<i64>>) -> Result<i64, Error> {
+        checksum(rows, Checksum::Difference)
+    }
+
+    fn part2(rows: &Vec<Vec<i64>>) -> Result<i64, Error> {
+        checksum(rows, Checksum::Division)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::Differential;
 
     #[test]
     fn it_works() {
@@ -65,29 +287,135 @@ mod tests {
         9 4 7 3
         3 8 6 5
         "#;
-        assert_eq!(calculate_checksum(&input), 9)
+        assert_eq!(calculate_checksum(input, Checksum::Division).unwrap(), 9)
     }
 
     #[test]
-    fn test_input() {
+    fn strategies() {
+        let rows = Day::parse("5 1 9 5\n7 5 3\n2 4 6 8\n").unwrap();
+        assert_eq!(checksum(&rows, Checksum::Difference).unwrap(), 18);
+        assert_eq!(checksum(&rows, Checksum::Custom(&|row| row.iter().sum())).unwrap(), 55);
+        assert!(checksum(&[vec![]], Checksum::Difference).is_err());
+        let first = |row: &[i64]| row[0];
+        assert_eq!(calculate_checksum("5 9 2 8\n9 4 7 3", Checksum::Custom(&first)).unwrap(), 14);
+        assert_eq!(calculate_checksum("5 9 2 8\n9 4 7 3", Checksum::Difference).unwrap(), 13);
+    }
+
+    #[test]
+    fn divisible_pairs() {
+        let rows = Day::parse("5 9 2 8\n9 4 7 3\n3 8 6 5\n").unwrap();
+        assert_eq!(find_pairs(&rows).unwrap(),
+                   vec![Pair { row: 0, dividend: 8, divisor: 2 },
+                        Pair { row: 1, dividend: 9, divisor: 3 },
+                        Pair { row: 2, dividend: 6, divisor: 3 }]);
+        assert_eq!(find_divisible(4, &[7, 7, 3]).unwrap_err(),
+                   Error::NoAnswer("row 4 has no two values that divide evenly".into()));
+        // each copy still divides other values
+        assert_eq!(find_divisible(4, &[7, 7, 3, 14]).unwrap_err(),
+                   Error::NoAnswer("row 4 has more than one pair that divides evenly: 14/7, 14/7".into()));
+        assert_eq!(find_divisible(0, &[0, 5, 3]).unwrap_err(),
+                   Error::NoAnswer("row 0 has no two values that divide evenly".into()));
+        assert_eq!(find_divisible(2, &[8, 3, 4, 6]).unwrap_err(),
+                   Error::NoAnswer("row 2 has more than one pair that divides evenly: 6/3, 8/4".into()));
+        assert_eq!(find_divisible(1, &[3, 7, -3, 3]).unwrap_err(),
+                   Error::NoAnswer("row 1 has more than one pair that divides evenly: 3/-3, -3/3".into()));
+        assert_eq!(find_divisible(0, &vec![5; 50_000]).unwrap_err(),
+                   Error::NoAnswer("row 0 has no two values that divide evenly".into()));
+        let mut fives = vec![5; 50_000];
+        fives.push(10);
+        assert_eq!(find_divisible(0, &fives).unwrap_err(),
+                   Error::NoAnswer("row 0 has more than one pair that divides evenly: 10/5, 10/5".into()));
+    }
+
+    #[test]
+    fn divisions_match_every_pair() {
+        let every_pair = |row: &Vec<i64>| {
+            let mut pairs = vec![];
+            for i in 0..row.len() {
+                for j in i + 1..row.len() {
+                    let (l, r) = if row[i].abs() >= row[j].abs() { (row[i], row[j]) } else { (row[j], row[i]) };
+                    if r != 0 && l != r && l % r == 0 {
+                        pairs.push((l, r));
+                    }
+                }
+            }
+            pairs.sort();
+            pairs
+        };
+        let sorted = |row: &Vec<i64>| {
+            let mut pairs = even_divisions(row, usize::MAX);
+            pairs.sort();
+            pairs
+        };
+        Differential::new(300).run(|rng| (0..rng.below(12)).map(|_| rng.between(-40, 40)).collect::<Vec<_>>(),
+                                   every_pair,
+                                   sorted);
+    }
+
+    #[test]
+    fn signed_pairs() {
+        assert_eq!(find_divisible(0, &[-9, 4, 3, 7]).unwrap().quotient().unwrap(), -3);
+        assert_eq!(find_divisible(2, &[i64::MIN, -1]).unwrap().quotient().unwrap_err(),
+                   Error::NoAnswer(format!("row 2 overflows dividing {} by -1", i64::MIN)));
+        assert_eq!(checksum(&[vec![i64::MIN, i64::MAX]], Checksum::Difference).unwrap_err(),
+                   Error::NoAnswer(format!("row 0 overflows taking {} from {}", i64::MIN, i64::MAX)));
+        assert_eq!(checksum(&[vec![0, i64::MAX], vec![0, 1]], Checksum::Difference).unwrap_err(),
+                   Error::NoAnswer("the checksum overflows at row 1".into()));
+        assert_eq!(find_divisible(0, &[-4, 4, 7]).unwrap(), Pair { row: 0, dividend: -4, divisor: 4 });
+        assert_eq!(checksum(&[vec![-5, 1, 9], vec![-2, -7]], Checksum::Difference).unwrap(), 19);
+    }
+
+    #[test]
+    fn spreadsheets() {
+        let csv = "a,b,c,d\n5,9,,8\n\n-9, 4 ,7,3\n,,,\n";
+        assert_eq!(read_spreadsheet(csv, &Format::csv()).unwrap(), vec![vec![5, 9, 8], vec![-9, 4, 7, 3]]);
+        let tsv = "a\tb\n1\t\t2\n";
+        assert_eq!(read_spreadsheet(tsv, &Format::tsv()).unwrap(), vec![vec![1, 2]]);
+        let no_header = Format { header: false, ..Format::csv() };
+        assert_eq!(read_spreadsheet("1,2\n3,+4", &no_header).unwrap(), vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(read_spreadsheet(csv, &no_header).unwrap_err(), ParseError::new(1, 1, "a", "a number"));
+        assert_eq!(read_spreadsheet("x\n1,2 3", &Format::csv()).unwrap_err(),
+                   ParseError::new(2, 3, "2 3", "a number"));
+    }
+
+    #[test]
+    fn row_by_row() {
+        let rows = read_spreadsheet("5,9,2,8\n9,-4,7,3\n3,4,6,8\n", &Format { header: false, ..Format::csv() }).unwrap();
+        let report = report(&rows, Checksum::Difference).unwrap();
+        assert_eq!(report.total, Ok(7 + 13 + 5));
+        assert_eq!(report.rows[1],
+                   RowReport { row: 1, min: -4, max: 9, pair: Ok(Pair { row: 1, dividend: 9, divisor: 3 }), contribution: Ok(13) });
+        assert_eq!(report.rows[2].pair,
+                   Err(Error::NoAnswer("row 2 has more than one pair that divides evenly: 6/3, 8/4".into())));
+        let table = report.to_string();
+        assert!(table.lines().nth(2).unwrap().ends_with("9/3            13"), "{}", table);
+        assert!(table.lines().last().unwrap().starts_with("total") && table.ends_with(" 25\n"), "{}", table);
+
+        // the row with two pairs fails the total but not the rows before it
+        let division = super::report(&rows, Checksum::Division).unwrap();
+        let two_pairs = Err(Error::NoAnswer("row 2 has more than one pair that divides evenly: 6/3, 8/4".into()));
+        assert_eq!(division.rows.iter().map(|r| r.contribution.clone()).collect::<Vec<_>>(),
+                   vec![Ok(4), Ok(3), two_pairs.clone()]);
+        assert_eq!(division.total, two_pairs);
+        let table = division.to_string();
+        assert!(table.lines().nth(3).unwrap().ends_with("-             -"), "{}", table);
+        assert!(table.ends_with("total                                                        -\n"), "{}", table);
+    }
+
+    #[test]
+    fn min_max() {
         let input = r#"
-        626    2424    2593    139    2136    163    1689    367    2235    125    2365    924    135    2583    1425    2502
-        183    149    3794    5221    5520    162    5430    4395    2466    1888    3999    3595    195    181    6188    4863
-        163    195    512    309    102    175    343    134    401    372    368    321    350    354    183    490
-        2441    228    250    2710    200    1166    231    2772    1473    2898    2528    2719    1736    249    1796    903
-        3999    820    3277    3322    2997    1219    1014    170    179    2413    183    3759    3585    2136    3700    188
-        132    108    262    203    228    104    205    126    69    208    235    311    313    258    110    117
-        963    1112    1106    50    186    45    154    60    1288    1150    986    232    872    433    48    319
-        111    1459    98    1624    2234    2528    93    1182    97    583    2813    3139    1792    1512    1326    3227
-        371    374    459    83    407    460    59    40    42    90    74    163    494    250    488    444
-        1405    2497    2079    2350    747    1792    2412    2615    89    2332    1363    102    81    2346    122    1356
-        1496    2782    2257    2258    961    214    219    2998    400    230    2676    3003    2955    254    2250    2707
-        694    669    951    455    2752    216    1576    3336    251    236    222    2967    3131    3456    1586    1509
-        170    2453    1707    2017    2230    157    2798    225    1891    945    943    2746    186    206    2678    2156
-        3632    3786    125    2650    1765    1129    3675    3445    1812    3206    99    105    1922    112    1136    3242
-        6070    6670    1885    1994    178    230    5857    241    253    5972    7219    252    806    6116    4425    3944
-        2257    155    734    228    204    2180    175    2277    180    2275    2239    2331    2278    1763    112    2054
+        5 1 9 5
+        7 5 3
+        2 4 6 8
         "#;
-        println!("solution: {:#?}", calculate_checksum(&input));
+        assert_eq!(Day::part1(&Day::parse(input).unwrap()).unwrap(), 18);
+    }
+
+    #[test]
+    fn bad_number() {
+        let input = "5 1 9 5\n7 5x 3\n";
+        assert_eq!(Day::parse(input).unwrap_err(),
+                   Error::Parse(ParseError::new(2, 3, "5x", "a number")));
     }
 }